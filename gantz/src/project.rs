@@ -101,6 +101,7 @@ pub enum NodeKind {
 /// This can be thought of as a node that is a graph composed of other nodes.
 #[derive(Deserialize, Serialize)]
 pub struct ProjectGraph {
+    #[serde(with = "graph::format::graph_node")]
     pub graph: NodeIdGraphNode,
     pub package_id: cargo::core::PackageId,
 }
@@ -113,6 +114,28 @@ pub enum NodeRef<'a> {
     Graph(ProjectNodeRefGraphNode<'a>),
 }
 
+/// A summary of the changes made by `Project::replace_node`.
+#[derive(Clone, Debug, Default)]
+pub struct ReplaceNode {
+    /// The indices of all replaced instances, grouped by the graph node in which they reside.
+    pub replaced: Vec<(NodeId, Vec<NodeIndex>)>,
+    /// Edges that could not be remapped onto the replacement node and were removed.
+    pub removed_edges: Vec<RemovedEdge>,
+}
+
+/// An edge removed by `Project::replace_node` as the replacement node has no matching port.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RemovedEdge {
+    /// The graph node in which the edge resided.
+    pub graph: NodeId,
+    /// The source node of the edge.
+    pub source: NodeIndex,
+    /// The destination node of the edge.
+    pub target: NodeIndex,
+    /// The edge's output and input.
+    pub edge: Edge,
+}
+
 /// Errors that may occur while creating a node crate.
 #[derive(Debug, Error)]
 pub enum OpenNodePackageError {
@@ -339,6 +362,38 @@ pub enum UpdateGraphError {
     },
 }
 
+/// Errors that might occur while replacing a node throughout a graph.
+#[derive(Debug, Error)]
+pub enum ReplaceNodeError {
+    #[error("no node within the collection for the given `NodeId`")]
+    NoMatchingNode { id: NodeId },
+    #[error("the replacement graph node would be nested within itself")]
+    RecursiveGraph { id: NodeId },
+    #[error("the replacement node has no state and cannot replace an inlet or outlet")]
+    StatelessPort { graph: NodeId },
+    #[error("failed to update graph: {err}")]
+    UpdateGraph {
+        #[from]
+        err: UpdateGraphError,
+    },
+}
+
+/// Errors that might occur while reordering the inlets and outlets of a graph node.
+#[derive(Debug, Error)]
+pub enum ReorderPortsError {
+    #[error("no graph node within the collection for the given `NodeId`")]
+    NoMatchingGraph { id: NodeId },
+    #[error("the given inlets are not a permutation of the graph node's inlets")]
+    InvalidInlets,
+    #[error("the given outlets are not a permutation of the graph node's outlets")]
+    InvalidOutlets,
+    #[error("failed to update graph: {err}")]
+    UpdateGraph {
+        #[from]
+        err: UpdateGraphError,
+    },
+}
+
 /// Node crates within the project workspace are prefixed with this.
 pub const NODE_CRATE_PREFIX: &'static str = "gantz_node_";
 
@@ -476,6 +531,38 @@ impl Project {
         Some(id_graph_to_node_graph(g, &self.nodes))
     }
 
+    /// The entrypoints generated for the graph node at the given **NodeId**.
+    ///
+    /// Each entrypoint names a function that may be loaded from the graph node's dynamic library
+    /// in order to begin evaluation.
+    ///
+    /// Returns `None` if there are no nodes for the given **NodeId** or if a node exists but is
+    /// not a **Graph** node.
+    pub fn entrypoints(&self, id: &NodeId) -> Option<Vec<graph::codegen::Entrypoint<NodeIndex>>> {
+        let g = self.ref_graph_node(id)?;
+        Some(graph::codegen::entrypoints(
+            &g.graph.graph,
+            &g.inlets,
+            &g.outlets,
+        ))
+    }
+
+    /// Non-fatal issues within the graph node at the given **NodeId**.
+    ///
+    /// Returns `None` if there are no nodes for the given **NodeId** or if a node exists but is
+    /// not a **Graph** node.
+    pub fn warnings(
+        &self,
+        id: &NodeId,
+    ) -> Option<Vec<graph::codegen::Warning<NodeIndex, EdgeIndex>>> {
+        let g = self.ref_graph_node(id)?;
+        Some(graph::codegen::warnings(
+            &g.graph.graph,
+            &g.inlets,
+            &g.outlets,
+        ))
+    }
+
     /// Update the graph associated with the graph node at the given **NodeId**.
     pub fn update_graph<F>(&mut self, id: &NodeId, update: F) -> Result<(), UpdateGraphError>
     where
@@ -496,6 +583,164 @@ impl Project {
         Ok(())
     }
 
+    /// Replace all instances of the node `from` with the node `to` throughout the graph node at
+    /// the given `id`, including all graph nodes nested within it.
+    ///
+    /// Edges are remapped by port index. Edges connected to a port that does not exist on `to`
+    /// are removed and listed within the returned **ReplaceNode** summary.
+    ///
+    /// Only graphs that contain an instance of `from` are modified. These graphs are regenerated
+    /// and recompiled, followed by each graph between them and `id`, children first.
+    ///
+    /// Returns an error if either `from` or `to` is not within the node collection, if `to` would
+    /// end up nested within itself, or if `to` has no state but would replace an inlet or outlet.
+    pub fn replace_node(
+        &mut self,
+        id: &NodeId,
+        from: &NodeId,
+        to: &NodeId,
+    ) -> Result<ReplaceNode, ReplaceNodeError> {
+        if !self.nodes.contains_key(from) {
+            return Err(ReplaceNodeError::NoMatchingNode { id: *from });
+        }
+        let (n_inputs, n_outputs) = match node_io(&self.nodes, to) {
+            Some(io) => io,
+            None => return Err(ReplaceNodeError::NoMatchingNode { id: *to }),
+        };
+        let (from, to) = (*from, *to);
+
+        // Collect the graphs containing `from` and check the replacement is valid within each.
+        let order = self.nodes.nested_graph_ids_children_first(id);
+        let to_nested = self.nodes.nested_graph_ids(&to);
+        let to_has_state = node_has_state(&self.nodes, &to);
+        let mut modified = vec![];
+        for &graph_id in &order {
+            let g = match self.nodes.id_graph(&graph_id) {
+                Some(g) => &g.graph,
+                None => continue,
+            };
+            if !g.node_indices().any(|n| g[n] == from) {
+                continue;
+            }
+            if to_nested.contains(&graph_id) {
+                return Err(ReplaceNodeError::RecursiveGraph { id: to });
+            }
+            let is_port = |n: &NodeIndex| g[*n] == from;
+            if !to_has_state && (g.inlets.iter().any(is_port) || g.outlets.iter().any(is_port)) {
+                return Err(ReplaceNodeError::StatelessPort { graph: graph_id });
+            }
+            modified.push(graph_id);
+        }
+
+        // Update the modified graphs along with every graph that nests them.
+        let mut summary = ReplaceNode::default();
+        for graph_id in order {
+            if !modified.contains(&graph_id) {
+                let nested = self.nodes.nested_graph_ids(&graph_id);
+                if modified.iter().any(|m| nested.contains(m)) {
+                    self.update_graph(&graph_id, |_| ())?;
+                }
+                continue;
+            }
+            let mut replaced = vec![];
+            let removed_edges = &mut summary.removed_edges;
+            self.update_graph(&graph_id, |g| {
+                replaced = g.node_indices().filter(|&n| g[n] == from).collect();
+                for &n in &replaced {
+                    g[n] = to;
+                }
+                let unmapped: Vec<_> = g
+                    .edge_indices()
+                    .filter_map(|e| {
+                        let (source, target) = g.edge_endpoints(e)?;
+                        let edge = g[e];
+                        let bad_output = replaced.contains(&source) && edge.output.0 >= n_outputs;
+                        let bad_input = replaced.contains(&target) && edge.input.0 >= n_inputs;
+                        match bad_output || bad_input {
+                            true => Some((e, source, target, edge)),
+                            false => None,
+                        }
+                    })
+                    .collect();
+                for (e, source, target, edge) in unmapped {
+                    g.remove_edge(e);
+                    removed_edges.push(RemovedEdge {
+                        graph: graph_id,
+                        source,
+                        target,
+                        edge,
+                    });
+                }
+            })?;
+            summary.replaced.push((graph_id, replaced));
+        }
+        Ok(summary)
+    }
+
+    /// Reorder the inlets and outlets of the graph node at the given `id`.
+    ///
+    /// `inlets` and `outlets` must be permutations of the graph node's current inlets and outlets.
+    /// Edges connected to instances of the graph node within other graphs are remapped so that
+    /// they remain connected to the same inlet or outlet node rather than the same index. Edges
+    /// connected to an inlet or outlet index that does not exist are left unchanged.
+    ///
+    /// The graph node is regenerated and recompiled, followed by each graph that instantiates it.
+    pub fn reorder_ports(
+        &mut self,
+        id: &NodeId,
+        inlets: &[NodeIndex],
+        outlets: &[NodeIndex],
+    ) -> Result<(), ReorderPortsError> {
+        let (input_map, output_map) = match self.nodes.id_graph(id) {
+            None => return Err(ReorderPortsError::NoMatchingGraph { id: *id }),
+            Some(g) => {
+                let input_map = port_permutation(&g.graph.inlets, inlets)
+                    .ok_or(ReorderPortsError::InvalidInlets)?;
+                let output_map = port_permutation(&g.graph.outlets, outlets)
+                    .ok_or(ReorderPortsError::InvalidOutlets)?;
+                (input_map, output_map)
+            }
+        };
+        self.update_graph(id, |g| {
+            g.inlets = inlets.to_vec();
+            g.outlets = outlets.to_vec();
+        })?;
+        let id = *id;
+        let parent_ids: Vec<NodeId> = self
+            .nodes
+            .iter()
+            .filter_map(|(&parent_id, n)| {
+                let g = n.graph()?;
+                match g.graph.node_indices().any(|n| g.graph[n] == id) {
+                    true => Some(parent_id),
+                    false => None,
+                }
+            })
+            .collect();
+        for parent_id in parent_ids {
+            self.update_graph(&parent_id, |g| {
+                let edges: Vec<_> = g.edge_indices().collect();
+                for e in edges {
+                    let (source, target) = g.edge_endpoints(e).expect("no endpoints for edge");
+                    let (from_id, to_id) = (g[source] == id, g[target] == id);
+                    // Edges to ports that don't exist are left as they are.
+                    let edge = &mut g[e];
+                    if from_id {
+                        if let Some(&output) = output_map.get(edge.output.0 as usize) {
+                            edge.output.0 = output;
+                        }
+                    }
+                    if to_id {
+                        if let Some(&input) = input_map.get(edge.input.0 as usize) {
+                            edge.input.0 = input;
+                        }
+                    }
+                }
+            })?;
+        }
+        Ok(())
+    }
+
     /// The path to the generated dynamic library for the graph node at the given `id`.
     ///
     /// Returns `None` if there is no dynamic library or no graph node for the given `id`.
@@ -601,6 +846,46 @@ impl NodeCollection {
         self.map.get_mut(id).and_then(|n| n.graph_mut())
     }
 
+    // The given graph node ID followed by the IDs of all graph nodes nested within it.
+    //
+    // Each graph node ID is only yielded once, even if it is instantiated many times.
+    fn nested_graph_ids(&self, id: &NodeId) -> Vec<NodeId> {
+        let mut ids = vec![];
+        let mut stack = vec![*id];
+        while let Some(id) = stack.pop() {
+            let g = match self.id_graph(&id) {
+                Some(g) if !ids.contains(&id) => g,
+                _ => continue,
+            };
+            ids.push(id);
+            stack.extend(g.graph.node_indices().map(|n| g.graph[n]));
+        }
+        ids
+    }
+
+    // The same as `nested_graph_ids`, but ordered such that each graph precedes all graphs in
+    // which it is nested.
+    fn nested_graph_ids_children_first(&self, id: &NodeId) -> Vec<NodeId> {
+        fn visit(
+            ns: &NodeCollection,
+            id: NodeId,
+            visited: &mut HashSet<NodeId>,
+            ids: &mut Vec<NodeId>,
+        ) {
+            let g = match ns.id_graph(&id) {
+                Some(g) if visited.insert(id) => g,
+                _ => return,
+            };
+            for n in g.graph.node_indices() {
+                visit(ns, g.graph[n], visited, ids);
+            }
+            ids.push(id);
+        }
+        let mut ids = vec![];
+        visit(self, *id, &mut HashSet::new(), &mut ids);
+        ids
+    }
+
     // The same as `id_graph`, but returns the fully referenced graph without the `NodeId`
     // indirection.
     fn ref_graph(&self, id: &NodeId) -> Option<ProjectNodeRefGraphNode> {
@@ -714,7 +999,7 @@ impl<'a> graph::EvaluatorFnBlock for ProjectNodeRefGraph<'a> {
                 syn::parse_quote! {
                     (#(
                         node_states[#outlet_state_indices]
-                            .downcast_ref::<#outlet_types()>
+                            .downcast_ref::<#outlet_types>()
                             .expect("unexpected outlet type")
                             .clone()
                     ),*)
@@ -810,6 +1095,34 @@ impl<'a> Node for NodeRef<'a> {
         }
     }
 
+    fn input_name(&self, input: node::Input) -> Option<String> {
+        match self {
+            NodeRef::Core(node) => node.input_name(input),
+            NodeRef::Graph(graph) => graph.input_name(input),
+        }
+    }
+
+    fn output_name(&self, output: node::Output) -> Option<String> {
+        match self {
+            NodeRef::Core(node) => node.output_name(output),
+            NodeRef::Graph(graph) => graph.output_name(output),
+        }
+    }
+
+    fn input_ty(&self, input: node::Input) -> Option<syn::Type> {
+        match self {
+            NodeRef::Core(node) => node.input_ty(input),
+            NodeRef::Graph(graph) => graph.input_ty(input),
+        }
+    }
+
+    fn output_ty(&self, output: node::Output) -> Option<syn::Type> {
+        match self {
+            NodeRef::Core(node) => node.output_ty(output),
+            NodeRef::Graph(graph) => graph.output_ty(output),
+        }
+    }
+
     fn crate_deps(&self) -> Vec<node::CrateDep> {
         match self {
             NodeRef::Core(node) => node.crate_deps(),
@@ -1025,7 +1338,7 @@ where
     let mut toml: toml::Value = toml::from_slice(&bytes)?;
     update(&mut toml);
     let toml_string = toml::to_string_pretty(&toml)?;
-    fs::write(&toml_path, &toml_string)?;
+    write_if_changed(&toml_path, toml_string.as_bytes())?;
     Ok(())
 }
 
@@ -1207,6 +1520,44 @@ fn id_graph_to_node_graph<'a>(
     }
 }
 
+// The number of inputs and outputs of the node with the given ID.
+fn node_io(ns: &NodeCollection, id: &NodeId) -> Option<(u32, u32)> {
+    match ns.get(id)? {
+        NodeKind::Core(ref node) => {
+            let eval = node.node().evaluator();
+            Some((eval.n_inputs(), eval.n_outputs()))
+        }
+        NodeKind::Graph(ref g) => Some((g.graph.inlets.len() as u32, g.graph.outlets.len() as u32)),
+    }
+}
+
+// Whether or not the node with the given ID has a state type, as required by inlets and outlets.
+fn node_has_state(ns: &NodeCollection, id: &NodeId) -> bool {
+    match ns.get(id) {
+        Some(NodeKind::Core(ref node)) => node.node().state_type().is_some(),
+        Some(NodeKind::Graph(_)) => true,
+        None => false,
+    }
+}
+
+// Maps each index within `old` to the index of the same port within `new`.
+//
+// Returns `None` if `new` is not a permutation of `old`.
+fn port_permutation(old: &[NodeIndex], new: &[NodeIndex]) -> Option<Vec<u32>> {
+    if old.len() != new.len() {
+        return None;
+    }
+    let mut map = Vec::with_capacity(old.len());
+    for port in old {
+        let ix = new.iter().position(|p| p == port)?;
+        if map.contains(&(ix as u32)) {
+            return None;
+        }
+        map.push(ix as u32);
+    }
+    Some(map)
+}
+
 // Given a graph node, generate the src for the graph.
 fn graph_node_src(g: &ProjectNodeRefGraphNode) -> syn::File {
     graph::codegen::file(&g.graph.graph, &g.inlets, &g.outlets)
@@ -1308,6 +1659,22 @@ where
     let node_crate_lib_rs = node_crate_lib_rs(node_crate_src(node_crate_dir));
     let src_string = format!("{}", file.into_token_stream());
     let src_bytes = src_string.as_bytes();
-    std::fs::write(&node_crate_lib_rs, src_bytes)?;
+    write_if_changed(&node_crate_lib_rs, src_bytes)?;
     Ok(())
 }
+
+// Write the given bytes to the file at the given path, but only if they differ from the existing
+// contents.
+//
+// Avoiding redundant writes keeps file modification times stable, which in turn prevents cargo
+// from rebuilding crates whose source has not changed.
+fn write_if_changed<P>(path: P, bytes: &[u8]) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    match fs::read(path) {
+        Ok(ref existing) if &existing[..] == bytes => Ok(()),
+        _ => fs::write(path, bytes),
+    }
+}
@@ -325,3 +325,112 @@ fn test_graph4_should_panic() {
         pull_eval_fn(&mut []);
     }
 }
+
+// Replace the `add` node from `test_graph1` with a single-input `double` node.
+//
+// The edge into the second input of `add` has no matching input on `double` and should be
+// removed, leaving `double(1) == 2`.
+#[test]
+fn test_replace_node() {
+    // Create a temp project.
+    let mut project = gantz::TempProject::open_with_name("test_replace_node").unwrap();
+
+    // Instantiate the nodes.
+    let push = node_push();
+    let one = node_int(1);
+    let add = node_add();
+    let double = node::expr("#v * 2").unwrap();
+    let two = node_int(2);
+    let assert_eq = node_assert_eq();
+
+    // Add the nodes to the project.
+    let push = project.add_core_node(Box::new(push) as Box<dyn SerdeNode>);
+    let one = project.add_core_node(Box::new(one) as Box<_>);
+    let add = project.add_core_node(Box::new(add) as Box<_>);
+    let double = project.add_core_node(Box::new(double) as Box<_>);
+    let two = project.add_core_node(Box::new(two) as Box<_>);
+    let assert_eq = project.add_core_node(Box::new(assert_eq) as Box<_>);
+
+    // Compose the graph.
+    let root = project.root_node_id();
+    let mut ixs = vec![];
+    project
+        .update_graph(&root, |g| {
+            let push = g.add_node(push);
+            let one = g.add_node(one);
+            let add = g.add_node(add);
+            let two = g.add_node(two);
+            let assert_eq = g.add_node(assert_eq);
+            g.add_edge(push, one, Edge::from((0, 0)));
+            g.add_edge(push, two, Edge::from((0, 0)));
+            g.add_edge(one, add, Edge::from((0, 0)));
+            g.add_edge(one, add, Edge::from((0, 1)));
+            g.add_edge(add, assert_eq, Edge::from((0, 0)));
+            g.add_edge(two, assert_eq, Edge::from((0, 1)));
+            ixs = vec![one, add];
+        })
+        .unwrap();
+
+    // Replace `add` with `double`.
+    let summary = project.replace_node(&root, &add, &double).unwrap();
+    let (one_ix, add_ix) = (ixs[0], ixs[1]);
+    assert_eq!(summary.replaced, vec![(root, vec![add_ix])]);
+    assert_eq!(summary.removed_edges.len(), 1);
+    let removed = summary.removed_edges[0];
+    assert_eq!((removed.source, removed.target), (one_ix, add_ix));
+    assert_eq!(removed.edge, Edge::from((0, 1)));
+
+    // Retrieve the path to the compiled library.
+    let dylib_path = project
+        .graph_node_dylib(&root)
+        .unwrap()
+        .expect("no dylib or node");
+    let lib = libloading::Library::new(&dylib_path).expect("failed to load library");
+    let symbol_name = "push".as_bytes();
+    unsafe {
+        let push_eval_fn: libloading::Symbol<fn(&mut [&mut dyn std::any::Any])> =
+            lib.get(symbol_name).expect("failed to load symbol");
+        // Execute the gantz graph.
+        push_eval_fn(&mut []);
+    }
+}
+
+// Updating a graph without changing it should not rewrite the node crate's files, as doing so would
+// cause cargo to needlessly rebuild the crate.
+#[test]
+fn test_update_graph_unchanged() {
+    // Create a temp project.
+    let mut project = gantz::TempProject::open_with_name("test_update_graph_unchanged").unwrap();
+
+    // Compose a simple graph.
+    let push = project.add_core_node(Box::new(node_push()) as Box<dyn SerdeNode>);
+    let one = project.add_core_node(Box::new(node_int(1)) as Box<_>);
+    let root = project.root_node_id();
+    project
+        .update_graph(&root, |g| {
+            let push = g.add_node(push);
+            let one = g.add_node(one);
+            g.add_edge(push, one, Edge::from((0, 0)));
+        })
+        .unwrap();
+
+    // Locate the root node crate's files.
+    let package_id = project.graph_node(&root).unwrap().package_id;
+    let crate_dir = package_id.source_id().url().to_file_path().unwrap();
+    let paths = [
+        crate_dir.join("src").join("lib.rs"),
+        crate_dir.join("Cargo.toml"),
+    ];
+    let mtimes = || -> Vec<_> {
+        paths
+            .iter()
+            .map(|p| std::fs::metadata(p).unwrap().modified().unwrap())
+            .collect()
+    };
+    let before = mtimes();
+
+    // Ensure any rewrite would produce a distinct modification time.
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    project.update_graph(&root, |_| ()).unwrap();
+    assert_eq!(before, mtimes());
+}
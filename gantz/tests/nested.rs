@@ -1,4 +1,5 @@
-use gantz::node::{self, SerdeNode, WithPushEval};
+use gantz::node::{self, SerdeNode, WithPushEval, WithStateType};
+use gantz::project::ReplaceNodeError;
 use gantz::Edge;
 
 fn node_push() -> node::Push<node::Expr> {
@@ -170,3 +171,328 @@ fn test_graph_nested_stateless() {
         }
     }
 }
+
+// Reorder the inlets and outlets of a nested graph and ensure edges within the parent follow them.
+//
+// GRAPH A
+//
+//    --------- ---------
+//    | Inlet | | Inlet |
+//    -+------- -+-------
+//     |         |
+//     |   -------
+//     |   |
+//    -+---+-
+//    | Sub |
+//    -+-----
+//     |
+//     |-----------
+//     |          |
+//    -+-------- -+--------
+//    | Outlet | | Outlet |
+//    ---------- ----------
+//
+// ROOT
+//
+//    --------
+//    | push | // push_eval
+//    -+------
+//     |
+//     |------
+//     |     |
+//    -+--- -+---  -----
+//    | 7 | | 6 |  | 5 |
+//    -+--- -+---  -+---
+//     |     |      |
+//    -+-----+------+-
+//    | GRAPH A      |
+//    -+----------+---
+//     |          |
+//    -+------   -+------
+//    | sink |   | sink |
+//    --------   --------
+//
+// The edge from `5` is unreachable and connects to an input that GRAPH A does not have.
+#[test]
+fn test_graph_nested_reorder_ports() {
+    // Create a temp project.
+    let mut project =
+        gantz::TempProject::open_with_name("test_graph_nested_reorder_ports").unwrap();
+
+    // Add the nodes to the project.
+    let push = project.add_core_node(Box::new(node_push()) as Box<dyn SerdeNode>);
+    let seven = project.add_core_node(Box::new(node_int(7)) as Box<_>);
+    let six = project.add_core_node(Box::new(node_int(6)) as Box<_>);
+    let five = project.add_core_node(Box::new(node::expr("5").unwrap()) as Box<_>);
+    let sub = project.add_core_node(Box::new(node::expr("#l - #r").unwrap()) as Box<_>);
+    let inlet = gantz::graph::Inlet::parse("i32").unwrap();
+    let outlet = gantz::graph::Outlet::parse("i32").unwrap();
+    let sink = project.add_core_node(Box::new(node::expr("{ #v; }").unwrap()) as Box<_>);
+    let inlet = project.add_core_node(Box::new(inlet) as _);
+    let outlet = project.add_core_node(Box::new(outlet) as _);
+    let graph_a = project
+        .add_graph_node(Default::default(), "graph_a")
+        .unwrap();
+
+    // Compose GRAPH A.
+    let mut inlets = vec![];
+    let mut outlets = vec![];
+    project
+        .update_graph(&graph_a, |g| {
+            let inlet_l = g.add_inlet(inlet);
+            let inlet_r = g.add_inlet(inlet);
+            let sub = g.add_node(sub);
+            let outlet_a = g.add_outlet(outlet);
+            let outlet_b = g.add_outlet(outlet);
+            g.add_edge(inlet_l, sub, Edge::from((0, 0)));
+            g.add_edge(inlet_r, sub, Edge::from((0, 1)));
+            g.add_edge(sub, outlet_a, Edge::from((0, 0)));
+            g.add_edge(sub, outlet_b, Edge::from((0, 0)));
+            inlets = vec![inlet_l, inlet_r];
+            outlets = vec![outlet_a, outlet_b];
+        })
+        .unwrap();
+
+    // Compose the root graph.
+    let root = project.root_node_id();
+    let mut ixs = vec![];
+    project
+        .update_graph(&root, |g| {
+            let push = g.add_node(push);
+            let seven = g.add_node(seven);
+            let six = g.add_node(six);
+            let graph_a = g.add_node(graph_a);
+            g.add_edge(push, seven, Edge::from((0, 0)));
+            g.add_edge(push, six, Edge::from((0, 0)));
+            g.add_edge(seven, graph_a, Edge::from((0, 0)));
+            g.add_edge(six, graph_a, Edge::from((0, 1)));
+            let five = g.add_node(five);
+            g.add_edge(five, graph_a, Edge::from((0, 5)));
+            let sink_a = g.add_node(sink);
+            let sink_b = g.add_node(sink);
+            g.add_edge(graph_a, sink_a, Edge::from((0, 0)));
+            g.add_edge(graph_a, sink_b, Edge::from((1, 0)));
+            ixs = vec![seven, six, graph_a, five, sink_a, sink_b];
+        })
+        .unwrap();
+
+    // Swap both the inlets and the outlets of GRAPH A.
+    let reordered_inlets = vec![inlets[1], inlets[0]];
+    let reordered_outlets = vec![outlets[1], outlets[0]];
+    project
+        .reorder_ports(&graph_a, &reordered_inlets, &reordered_outlets)
+        .unwrap();
+    let g = &project.graph_node(&graph_a).unwrap().graph;
+    assert_eq!(g.inlets, reordered_inlets);
+    assert_eq!(g.outlets, reordered_outlets);
+
+    // The root's edges should have followed the inlets.
+    let (seven_ix, six_ix, graph_a_ix) = (ixs[0], ixs[1], ixs[2]);
+    let g = &project.graph_node(&root).unwrap().graph;
+    let edge = |a, b| g[g.find_edge(a, b).expect("no edge")];
+    assert_eq!(edge(seven_ix, graph_a_ix), Edge::from((0, 1)));
+    assert_eq!(edge(six_ix, graph_a_ix), Edge::from((0, 0)));
+
+    // As should the edges leaving GRAPH A follow the outlets.
+    let (sink_a_ix, sink_b_ix) = (ixs[4], ixs[5]);
+    assert_eq!(edge(graph_a_ix, sink_a_ix), Edge::from((1, 0)));
+    assert_eq!(edge(graph_a_ix, sink_b_ix), Edge::from((0, 0)));
+
+    // Edges to inputs that don't exist are left unchanged.
+    let five_ix = ixs[3];
+    assert_eq!(edge(five_ix, graph_a_ix), Edge::from((0, 5)));
+
+    // A non-permutation should be rejected.
+    let invalid = vec![inlets[0], inlets[0]];
+    assert!(project
+        .reorder_ports(&graph_a, &invalid, &reordered_outlets)
+        .is_err());
+}
+
+// Replace the `Mul` node within GRAPH A with a stateful `Sub` node by replacing from the root graph.
+//
+// The `Sub` node's state shifts the position of GRAPH A's outlet within its state, so the root must
+// be regenerated along with GRAPH A.
+//
+// GRAPH A
+//
+//    --------- ---------
+//    | Inlet | | Inlet |
+//    -+------- -+-------
+//     |         |
+//     |   -------
+//     |   |
+//    -+---+-
+//    | Mul |
+//    -+-----
+//     |
+//    -+--------
+//    | Outlet |
+//    ----------
+//
+// ROOT
+//
+//    --------
+//    | push | // push_eval
+//    -+------
+//     |
+//     |------------
+//     |           |
+//     |------     |
+//     |     |     |
+//    -+--- -+---  |
+//    | 7 | | 6 |  |
+//    -+--- -+---  |
+//     |     |     |
+//     |     ---   |
+//     |       |   |
+//    -+-------+- -+---
+//    | GRAPH A | | 1 |
+//    -+--------- -+---
+//     |           |
+//     |         ---
+//     |         |
+//    -+---------+-
+//    | assert_eq |
+//    -------------
+#[test]
+fn test_graph_nested_replace_node() {
+    // Create a temp project.
+    let mut project = gantz::TempProject::open_with_name("test_graph_nested_replace_node").unwrap();
+
+    // Instantiate the nodes.
+    let sub = node::expr("{ *state += 1; (#l - #r) }")
+        .unwrap()
+        .with_state_ty("u32")
+        .unwrap();
+    let inlet = gantz::graph::Inlet::parse("i32").unwrap();
+    let outlet = gantz::graph::Outlet::parse("i32").unwrap();
+
+    // Add the nodes to the project.
+    let push = project.add_core_node(Box::new(node_push()) as Box<dyn SerdeNode>);
+    let seven = project.add_core_node(Box::new(node_int(7)) as Box<_>);
+    let six = project.add_core_node(Box::new(node_int(6)) as Box<_>);
+    let one = project.add_core_node(Box::new(node_int(1)) as Box<_>);
+    let mul = project.add_core_node(Box::new(node_mul()) as Box<_>);
+    let sub = project.add_core_node(Box::new(sub) as Box<_>);
+    let assert_eq = project.add_core_node(Box::new(node_assert_eq()) as Box<_>);
+    let inlet = project.add_core_node(Box::new(inlet) as _);
+    let outlet = project.add_core_node(Box::new(outlet) as _);
+    let graph_a = project
+        .add_graph_node(Default::default(), "graph_a")
+        .unwrap();
+
+    // Compose GRAPH A.
+    let mut mul_ix = None;
+    project
+        .update_graph(&graph_a, |g| {
+            let inlet_l = g.add_inlet(inlet);
+            let inlet_r = g.add_inlet(inlet);
+            let mul = g.add_node(mul);
+            let outlet = g.add_outlet(outlet);
+            g.add_edge(inlet_l, mul, Edge::from((0, 0)));
+            g.add_edge(inlet_r, mul, Edge::from((0, 1)));
+            g.add_edge(mul, outlet, Edge::from((0, 0)));
+            mul_ix = Some(mul);
+        })
+        .unwrap();
+
+    // Compose the root graph.
+    let root = project.root_node_id();
+    project
+        .update_graph(&root, |g| {
+            let push = g.add_node(push);
+            let seven = g.add_node(seven);
+            let six = g.add_node(six);
+            let graph_a = g.add_node(graph_a);
+            let one = g.add_node(one);
+            let assert_eq = g.add_node(assert_eq);
+            g.add_edge(push, seven, Edge::from((0, 0)));
+            g.add_edge(push, six, Edge::from((0, 0)));
+            g.add_edge(push, one, Edge::from((0, 0)));
+            g.add_edge(seven, graph_a, Edge::from((0, 0)));
+            g.add_edge(six, graph_a, Edge::from((0, 1)));
+            g.add_edge(graph_a, assert_eq, Edge::from((0, 0)));
+            g.add_edge(one, assert_eq, Edge::from((0, 1)));
+        })
+        .unwrap();
+
+    // Unknown nodes should be rejected on either side of the replacement.
+    let unknown: gantz::project::NodeId = serde_json::from_str("9999").unwrap();
+    assert!(project.replace_node(&root, &unknown, &sub).is_err());
+    assert!(project.replace_node(&root, &sub, &unknown).is_err());
+
+    // A graph node cannot be nested within itself.
+    for &to in &[graph_a, root] {
+        match project.replace_node(&root, &mul, &to) {
+            Err(ReplaceNodeError::RecursiveGraph { id }) => assert_eq!(id, to),
+            res => panic!("expected `RecursiveGraph`, found {:?}", res),
+        }
+    }
+
+    // Inlets and outlets can only be replaced by nodes with state.
+    match project.replace_node(&root, &inlet, &mul) {
+        Err(ReplaceNodeError::StatelessPort { graph }) => assert_eq!(graph, graph_a),
+        res => panic!("expected `StatelessPort`, found {:?}", res),
+    }
+
+    // Replace `Mul` from the root, which should reach the instance within GRAPH A.
+    let mul_ix = mul_ix.unwrap();
+    let summary = project.replace_node(&root, &mul, &sub).unwrap();
+    assert_eq!(summary.replaced, vec![(graph_a, vec![mul_ix])]);
+    assert!(summary.removed_edges.is_empty());
+    let g = &project.graph_node(&graph_a).unwrap().graph;
+    assert_eq!(g[mul_ix], sub);
+    assert_eq!(g.edge_count(), 3);
+
+    // Retrieve the paths to the compiled libraries.
+    let root_dylib_path = project
+        .graph_node_dylib(&root)
+        .unwrap()
+        .expect("no dylib or node");
+    let graph_a_dylib_path = project
+        .graph_node_dylib(&graph_a)
+        .unwrap()
+        .expect("no dylib or node");
+    let root_lib = libloading::Library::new(&root_dylib_path).expect("failed to load root library");
+    let graph_a_lib =
+        libloading::Library::new(&graph_a_dylib_path).expect("failed to load graph a library");
+    let symbol_name = "push".as_bytes();
+    unsafe {
+        let push_eval_fn: libloading::Symbol<fn(&mut [&mut dyn std::any::Any])> =
+            root_lib.get(symbol_name).expect("failed to load symbol");
+
+        let graph_a_full_eval: libloading::Symbol<fn(&mut [&mut dyn std::any::Any])> = graph_a_lib
+            .get(gantz::graph::FULL_EVAL_FN_NAME.as_bytes())
+            .expect("failed to load symbol");
+        let mut graph_a_full_eval: libloading::Symbol<'static, fn(&mut [&mut dyn std::any::Any])> =
+            std::mem::transmute(graph_a_full_eval);
+
+        let mut inlet0 = 0i32;
+        let mut inlet1 = 0i32;
+        let mut sub_count = 0u32;
+        let mut outlet0 = 0i32;
+
+        {
+            // The `Sub` node's state now sits between the inlets and the outlet.
+            let graph_a_node_state = &mut [
+                &mut inlet0 as &mut dyn std::any::Any,
+                &mut inlet1 as _,
+                &mut sub_count as _,
+                &mut outlet0 as _,
+            ][..];
+            let mut graph_a: (&'static mut [&'static mut dyn std::any::Any], *mut ()) = (
+                std::mem::transmute(graph_a_node_state),
+                &mut graph_a_full_eval as *mut _ as *mut _,
+            );
+
+            let node_states = &mut [&mut graph_a as &mut dyn std::any::Any];
+
+            // Execute the gantz graph.
+            push_eval_fn(node_states);
+        }
+
+        assert_eq!(sub_count, 1);
+        assert_eq!(outlet0, 1);
+    }
+}
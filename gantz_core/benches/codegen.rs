@@ -0,0 +1,51 @@
+// Benchmarks for the codegen hot paths, run against synthetic graphs of varying size.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use gantz_core::graph::codegen;
+use gantz_core::testing::RandomGraph;
+
+const SIZES: &[usize] = &[16, 64, 256, 1024];
+
+fn bench_file(c: &mut Criterion) {
+    let mut group = c.benchmark_group("codegen_file");
+    for &size in SIZES {
+        let g = RandomGraph::new(size).push_nodes(4).build();
+        let g = g.map(|_, n| n.node(), |_, e| *e);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &g, |b, g| {
+            b.iter(|| codegen::file(g, &[], &[]))
+        });
+    }
+    group.finish();
+}
+
+fn bench_eval_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_eval_order");
+    for &size in SIZES {
+        let g = RandomGraph::new(size).build();
+        let g = g.map(|_, n| n.node(), |_, e| *e);
+        let push = petgraph::graph::NodeIndex::new(0);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &g, |b, g| {
+            b.iter(|| codegen::push_eval_order(g, push).count())
+        });
+    }
+    group.finish();
+}
+
+fn bench_state_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("state_order");
+    for &size in SIZES {
+        let g = RandomGraph::new(size).build();
+        let g = g.map(|_, n| n.node(), |_, e| *e);
+        let push = petgraph::graph::NodeIndex::new(0);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &g, |b, g| {
+            b.iter(|| {
+                let order = codegen::push_eval_order(g, push);
+                codegen::state_order(g, order).count()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_file, bench_eval_order, bench_state_order);
+criterion_main!(benches);
@@ -1,8 +1,8 @@
 use super::Edge;
 use crate::node::{self, Node};
 use petgraph::visit::{
-    Data, Dfs, EdgeRef, GraphRef, IntoEdgesDirected, IntoNodeReferences, NodeIndexable, NodeRef,
-    Topo, Visitable, Walker,
+    Data, Dfs, EdgeRef, GraphRef, IntoEdgeReferences, IntoEdgesDirected, IntoNodeReferences,
+    NodeIndexable, NodeRef, Topo, Visitable, Walker,
 };
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
@@ -30,6 +30,54 @@ pub struct ExprInput<NI> {
     pub requires_clone: bool,
 }
 
+/// A function generated for a graph that may be called by the host to begin evaluation.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Entrypoint<NI> {
+    /// The kind of evaluation performed by the function.
+    pub kind: EntrypointKind<NI>,
+    /// The name of the generated function's symbol.
+    pub fn_name: String,
+}
+
+/// The kind of evaluation performed by an **Entrypoint**.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum EntrypointKind<NI> {
+    /// Full evaluation of a nested graph from all of its inlets to all of its outlets.
+    Full,
+    /// Push evaluation from the given node.
+    Push(NI),
+    /// Pull evaluation from the given node.
+    Pull(NI),
+    /// Simultaneous evaluation from multiple nodes whose eval functions share the same name.
+    Group { push: Vec<NI>, pull: Vec<NI> },
+}
+
+/// A set of nodes whose push and pull evaluation is combined into a single generated function.
+///
+/// Nodes are grouped by the name of their `push_eval` or `pull_eval` function. Evaluation order
+/// for a group is a topological ordering over the union of the components reachable from each of
+/// its nodes, as produced by `eval_order`.
+#[derive(Clone, Debug)]
+pub struct EvalGroup<NI> {
+    /// The function signature and attributes shared by all nodes within the group.
+    pub eval: node::EvalFn,
+    /// Nodes from which evaluation is pushed.
+    pub push: Vec<NI>,
+    /// Nodes from which evaluation is pulled.
+    pub pull: Vec<NI>,
+}
+
+/// A non-fatal issue within a graph, useful for reporting to the user after codegen.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Warning<NI, EI> {
+    /// The node is not reachable from any entrypoint and so is never evaluated.
+    Unreachable { node: NI },
+    /// The input has no incoming edge and will receive `()` during evaluation.
+    UnconnectedInput { node: NI, input: node::Input },
+    /// The edge refers to an output or input that does not exist on the connected nodes.
+    InvalidEdge { edge: EI },
+}
+
 /// Shorthand for the node evaluator map passed between codegen stages.
 pub type NodeEvaluatorMap<Id> = HashMap<Id, node::Evaluator>;
 
@@ -113,6 +161,48 @@ where
         .collect()
 }
 
+/// Group all push and pull evaluation nodes by the name of their eval function.
+///
+/// Groups are ordered by the first occurrence of each name, visiting pull nodes before push
+/// nodes. A name that is only used by a single node produces a group with a single node.
+///
+/// **Panic!**s if two nodes share an eval function name but their **EvalFn**s differ in any other
+/// way, e.g. different inputs or attributes, as they cannot share a single generated function.
+pub fn eval_groups<G>(g: G) -> Vec<EvalGroup<G::NodeId>>
+where
+    G: IntoNodeReferences,
+    G::NodeWeight: Node,
+{
+    let mut groups: Vec<EvalGroup<G::NodeId>> = vec![];
+    let pull = pull_nodes(g).into_iter().map(|(n, eval)| (n, eval, false));
+    let push = push_nodes(g).into_iter().map(|(n, eval)| (n, eval, true));
+    for (n, eval, is_push) in pull.chain(push) {
+        let ix = match groups
+            .iter()
+            .position(|group| group.eval.signature.ident == eval.signature.ident)
+        {
+            Some(ix) => {
+                assert!(
+                    groups[ix].eval == eval,
+                    "nodes sharing the eval fn name `{}` have differing signatures or attributes",
+                    eval.signature.ident,
+                );
+                ix
+            }
+            None => {
+                let (push, pull) = (vec![], vec![]);
+                groups.push(EvalGroup { eval, push, pull });
+                groups.len() - 1
+            }
+        };
+        match is_push {
+            true => groups[ix].push.push(n),
+            false => groups[ix].pull.push(n),
+        }
+    }
+    groups
+}
+
 /// An iterator yielding all nodes reachable via pushing from the given node.
 pub fn push_reachable<G>(g: G, n: G::NodeId) -> impl Iterator<Item = G::NodeId>
 where
@@ -512,6 +602,97 @@ where
         .collect()
 }
 
+/// Enumerate the entrypoints generated by `file` for the given graph.
+///
+/// Entrypoints are yielded in the same order in which their functions appear in the generated
+/// file. This allows hosts to discover the available symbols without reproducing the naming rules
+/// applied during codegen.
+pub fn entrypoints<G>(
+    g: G,
+    inlets: &[G::NodeId],
+    outlets: &[G::NodeId],
+) -> Vec<Entrypoint<G::NodeId>>
+where
+    G: IntoNodeReferences,
+    G::NodeWeight: Node,
+{
+    let full = match (inlets.is_empty(), outlets.is_empty()) {
+        (true, true) => None,
+        _ => Some((EntrypointKind::Full, super::full_eval_fn())),
+    };
+    let groups = eval_groups(g).into_iter().map(|group| {
+        let EvalGroup { eval, push, pull } = group;
+        let kind = match (&push[..], &pull[..]) {
+            (&[n], &[]) => EntrypointKind::Push(n),
+            (&[], &[n]) => EntrypointKind::Pull(n),
+            _ => EntrypointKind::Group { push, pull },
+        };
+        (kind, eval)
+    });
+    full.into_iter()
+        .chain(groups)
+        .map(|(kind, eval)| {
+            let fn_name = eval.signature.ident.to_string();
+            Entrypoint { kind, fn_name }
+        })
+        .collect()
+}
+
+/// Collect non-fatal issues within the given graph.
+///
+/// Warnings for each node are yielded in node order, followed by warnings for edges.
+pub fn warnings<G>(
+    g: G,
+    inlets: &[G::NodeId],
+    outlets: &[G::NodeId],
+) -> Vec<Warning<G::NodeId, G::EdgeId>>
+where
+    G: IntoEdgeReferences + IntoEdgesDirected + IntoNodeReferences + Visitable,
+    G: Data<EdgeWeight = Edge>,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Node,
+{
+    let node_evaluators = node_evaluators(g);
+    let mut reachable: HashSet<G::NodeId> = HashSet::new();
+    if !inlets.is_empty() || !outlets.is_empty() {
+        reachable.extend(eval_order(
+            g,
+            inlets.iter().cloned(),
+            outlets.iter().cloned(),
+        ));
+    }
+    for group in eval_groups(g) {
+        reachable.extend(eval_order(g, group.push, group.pull));
+    }
+
+    let mut warnings = vec![];
+    for n in g.node_references() {
+        let node = n.id();
+        if !reachable.contains(&node) {
+            warnings.push(Warning::Unreachable { node });
+        }
+        let connected: HashSet<node::Input> = g
+            .edges_directed(node, petgraph::Incoming)
+            .map(|e| e.weight().input)
+            .collect();
+        for i in 0..node_evaluators[&node].n_inputs() {
+            let input = node::Input(i);
+            if !connected.contains(&input) {
+                warnings.push(Warning::UnconnectedInput { node, input });
+            }
+        }
+    }
+    for e in g.edge_references() {
+        let w = e.weight();
+        let n_outputs = node_evaluators[&e.source()].n_outputs();
+        let n_inputs = node_evaluators[&e.target()].n_inputs();
+        if w.output.0 >= n_outputs || w.input.0 >= n_inputs {
+            warnings.push(Warning::InvalidEdge { edge: e.id() });
+        }
+    }
+    warnings
+}
+
 /// Given a gantz graph, generate the rust code src file with all the necessary functions for
 /// executing it.
 pub fn file<G>(g: G, inlets: &[G::NodeId], outlets: &[G::NodeId]) -> syn::File
@@ -535,22 +716,12 @@ where
         }
     };
 
-    let pull_nodes = pull_nodes(g);
-    let push_nodes = push_nodes(g);
-    let pull_node_eval_steps = pull_nodes.into_iter().map(|(n, eval)| {
-        let order = pull_eval_order(g, n);
+    let eval_group_steps = eval_groups(g).into_iter().map(|group| {
+        let order = eval_order(g, group.push, group.pull);
         let steps = eval_steps(g, &node_evaluators, order);
-        (steps, eval)
+        (steps, group.eval)
     });
-    let push_node_eval_steps = push_nodes.into_iter().map(|(n, eval)| {
-        let order = push_eval_order(g, n);
-        let steps = eval_steps(g, &node_evaluators, order);
-        (steps, eval)
-    });
-    let all_eval_steps = full_eval_steps
-        .into_iter()
-        .chain(pull_node_eval_steps)
-        .chain(push_node_eval_steps);
+    let all_eval_steps = full_eval_steps.into_iter().chain(eval_group_steps);
     let all_eval_fn_items = all_eval_steps.map(|(steps, eval)| {
         let stmts = eval_stmts(g, &steps, &node_state_types, &node_evaluators);
         let item_fn = eval_fn(eval, stmts);
@@ -0,0 +1,147 @@
+//! A versioned serialization format for gantz graphs.
+//!
+//! Graphs are serialized as a map with the following fields:
+//!
+//! - `version`: The version of the format. Data written before the format was versioned has no
+//!   `version` field and is treated as version `0`.
+//! - `nodes`: The node weights in index order, skipping vacant indices.
+//! - `node_holes`: The indices of vacant nodes. Storing these allows node indices to remain stable
+//!   across node removal.
+//! - `edge_property`: Always `"directed"`.
+//! - `edges`: A `[source, target, edge]` entry for each edge in index order, or `null` for each
+//!   vacant edge index.
+//!
+//! Unknown fields are ignored during deserialization so that fields may be added in later
+//! versions without breaking older readers. Data with a version newer than `VERSION` is rejected.
+//!
+//! The `serialize` and `deserialize` functions may be used with `#[serde(with = "...")]` on
+//! **StableGraph** fields. The `graph_node` module does the same for **GraphNode**s wrapped around
+//! a **StableGraph**.
+//!
+//! The `version` field is flattened alongside the graph's own fields via `#[serde(flatten)]`, so
+//! the format is only supported by self-describing serializers such as JSON. Formats that rely on
+//! the type to describe the data, such as bincode, cannot be used.
+
+use super::Edge;
+use petgraph::graph::IndexType;
+use petgraph::stable_graph::StableGraph;
+use petgraph::Directed;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The current version of the graph serialization format.
+pub const VERSION: u32 = 1;
+
+/// Serialize the given graph with the current format `VERSION`.
+pub fn serialize<N, Ix, S>(g: &StableGraph<N, Edge, Directed, Ix>, s: S) -> Result<S::Ok, S::Error>
+where
+    N: Serialize,
+    Ix: IndexType + Serialize,
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct Versioned<'a, G> {
+        version: u32,
+        #[serde(flatten)]
+        graph: &'a G,
+    }
+    let version = VERSION;
+    Versioned { version, graph: g }.serialize(s)
+}
+
+/// Deserialize a graph written with any supported format version.
+pub fn deserialize<'de, N, Ix, D>(d: D) -> Result<StableGraph<N, Edge, Directed, Ix>, D::Error>
+where
+    N: Deserialize<'de>,
+    Ix: IndexType + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Versioned<G> {
+        #[serde(default)]
+        version: u32,
+        #[serde(flatten)]
+        graph: G,
+    }
+    let Versioned { version, graph } = Versioned::deserialize(d)?;
+    match version {
+        // Version `0` predates the `version` field but is otherwise identical.
+        0 | VERSION => Ok(graph),
+        _ => Err(de::Error::custom(format!(
+            "unsupported graph format version {} (expected {} or lower)",
+            version, VERSION,
+        ))),
+    }
+}
+
+/// Serialize and deserialize a **GraphNode** using the versioned format for its inner graph.
+pub mod graph_node {
+    use crate::graph::{Edge, GraphNode};
+    use petgraph::graph::{IndexType, NodeIndex};
+    use petgraph::stable_graph::StableGraph;
+    use petgraph::Directed;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    type Graph<N, Ix> = StableGraph<N, Edge, Directed, Ix>;
+
+    /// Serialize the given **GraphNode**, writing its inner graph with the current format
+    /// `VERSION`.
+    pub fn serialize<N, Ix, S>(g: &GraphNode<Graph<N, Ix>>, s: S) -> Result<S::Ok, S::Error>
+    where
+        N: Serialize,
+        Ix: IndexType + Serialize,
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        #[serde(bound = "N: Serialize, Ix: IndexType + Serialize")]
+        struct Repr<'a, N, Ix: IndexType> {
+            #[serde(serialize_with = "serialize_graph")]
+            graph: &'a Graph<N, Ix>,
+            inlets: &'a [NodeIndex<Ix>],
+            outlets: &'a [NodeIndex<Ix>],
+        }
+        let repr = Repr {
+            graph: &g.graph,
+            inlets: &g.inlets,
+            outlets: &g.outlets,
+        };
+        repr.serialize(s)
+    }
+
+    /// Deserialize a **GraphNode** whose inner graph was written with any supported format
+    /// version.
+    pub fn deserialize<'de, N, Ix, D>(d: D) -> Result<GraphNode<Graph<N, Ix>>, D::Error>
+    where
+        N: Deserialize<'de>,
+        Ix: IndexType + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(bound = "N: Deserialize<'de>, Ix: IndexType + Deserialize<'de>")]
+        struct Repr<N, Ix: IndexType> {
+            #[serde(with = "super")]
+            graph: Graph<N, Ix>,
+            inlets: Vec<NodeIndex<Ix>>,
+            outlets: Vec<NodeIndex<Ix>>,
+        }
+        let Repr {
+            graph,
+            inlets,
+            outlets,
+        } = Repr::deserialize(d)?;
+        Ok(GraphNode {
+            graph,
+            inlets,
+            outlets,
+        })
+    }
+
+    // Allows for using `super::serialize` with a field that is a reference to a graph.
+    fn serialize_graph<N, Ix, S>(g: &&Graph<N, Ix>, s: S) -> Result<S::Ok, S::Error>
+    where
+        N: Serialize,
+        Ix: IndexType + Serialize,
+        S: Serializer,
+    {
+        super::serialize(*g, s)
+    }
+}
@@ -7,6 +7,7 @@ use syn::token::Comma;
 use syn::FnArg;
 
 pub mod codegen;
+pub mod format;
 
 /// Required by graphs that support nesting graphs of the same type as nodes.
 pub trait EvaluatorFnBlock: GraphBase {
@@ -78,6 +79,12 @@ pub struct Inlet {
     /// The expected type for this inlet.
     #[serde(with = "crate::node::serde::ty")]
     pub ty: syn::Type,
+    /// An optional name for this inlet.
+    ///
+    /// The enclosing **GraphNode** surfaces this as the name of its associated input. Codegen
+    /// also uses it to name the associated evaluator function argument where possible.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 /// An outlet from a nested graph.
@@ -86,6 +93,11 @@ pub struct Outlet {
     /// The expected type for this outlet.
     #[serde(with = "crate::node::serde::ty")]
     pub ty: syn::Type,
+    /// An optional name for this outlet.
+    ///
+    /// The enclosing **GraphNode** surfaces this as the name of its associated output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 impl Edge {
@@ -99,25 +111,39 @@ impl Edge {
 impl Inlet {
     /// Construct an inlet with the given type.
     pub fn new(ty: syn::Type) -> Self {
-        Inlet { ty }
+        let name = None;
+        Inlet { ty, name }
     }
 
     /// The same as `new` but parses the type from the given `str`.
     pub fn parse(ty: &str) -> syn::Result<Self> {
         Ok(Self::new(syn::parse_str(ty)?))
     }
+
+    /// Give the inlet the given name.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
 }
 
 impl Outlet {
     /// Construct an outlet with the given type.
     pub fn new(ty: syn::Type) -> Self {
-        Outlet { ty }
+        let name = None;
+        Outlet { ty, name }
     }
 
     /// The same as `new` but parses the type from the given `str`.
     pub fn parse(ty: &str) -> syn::Result<Self> {
         Ok(Self::new(syn::parse_str(ty)?))
     }
+
+    /// Give the outlet the given name.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
 }
 
 impl<G> GraphNode<G>
@@ -203,6 +229,26 @@ where
     fn state_type(&self) -> Option<syn::Type> {
         Some(Graph::state_type(&self.graph))
     }
+
+    fn input_name(&self, input: node::Input) -> Option<String> {
+        let &id = self.inlets.get(input.0 as usize)?;
+        self.graph.node(id)?.output_name(node::Output(0))
+    }
+
+    fn output_name(&self, output: node::Output) -> Option<String> {
+        let &id = self.outlets.get(output.0 as usize)?;
+        self.graph.node(id)?.input_name(node::Input(0))
+    }
+
+    fn input_ty(&self, input: node::Input) -> Option<syn::Type> {
+        let &id = self.inlets.get(input.0 as usize)?;
+        self.graph.node(id)?.output_ty(node::Output(0))
+    }
+
+    fn output_ty(&self, output: node::Output) -> Option<syn::Type> {
+        let &id = self.outlets.get(output.0 as usize)?;
+        self.graph.node(id)?.input_ty(node::Input(0))
+    }
 }
 
 impl<G> Default for GraphNode<G>
@@ -364,6 +410,20 @@ impl Node for Inlet {
     fn state_type(&self) -> Option<syn::Type> {
         Some(self.ty.clone())
     }
+
+    fn output_name(&self, output: node::Output) -> Option<String> {
+        match output {
+            node::Output(0) => self.name.clone(),
+            _ => None,
+        }
+    }
+
+    fn output_ty(&self, output: node::Output) -> Option<syn::Type> {
+        match output {
+            node::Output(0) => Some(self.ty.clone()),
+            _ => None,
+        }
+    }
 }
 
 impl Node for Outlet {
@@ -389,6 +449,20 @@ impl Node for Outlet {
     fn state_type(&self) -> Option<syn::Type> {
         Some(self.ty.clone())
     }
+
+    fn input_name(&self, input: node::Input) -> Option<String> {
+        match input {
+            node::Input(0) => self.name.clone(),
+            _ => None,
+        }
+    }
+
+    fn input_ty(&self, input: node::Input) -> Option<syn::Type> {
+        match input {
+            node::Input(0) => Some(self.ty.clone()),
+            _ => None,
+        }
+    }
 }
 
 impl<N, E, Ty, Ix> AddNode for petgraph::Graph<N, E, Ty, Ix>
@@ -535,12 +609,13 @@ fn graph_node_evaluator_fn_inputs<G>(g: G, inlets: &[G::NodeId]) -> Punctuated<F
 where
     G: Graph,
 {
+    let mut taken = std::collections::HashSet::new();
     inlets
         .iter()
         .enumerate()
         .map(|(i, &n)| {
-            let name = format!("inlet{}", i);
-            let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+            let name = g.node(n).and_then(|n| n.output_name(node::Output(0)));
+            let ident = graph_node_evaluator_fn_input_ident(name, i, &mut taken);
             let ty = expect_node_state_type(&g, n);
             let fn_arg: syn::FnArg = syn::parse_quote! { #ident: #ty };
             fn_arg
@@ -548,6 +623,23 @@ where
         .collect()
 }
 
+// The identifier for the evaluator function argument associated with the inlet at index `i`.
+//
+// Named inlets produce `inlet_<name>` as long as the result is a valid, unique identifier.
+// Otherwise, the inlet index is used, e.g. `inlet0`.
+fn graph_node_evaluator_fn_input_ident(
+    name: Option<String>,
+    i: usize,
+    taken: &mut std::collections::HashSet<String>,
+) -> syn::Ident {
+    let named = name
+        .map(|name| format!("inlet_{}", name))
+        .filter(|s| syn::parse_str::<syn::Ident>(s).is_ok() && !taken.contains(s));
+    let name = named.unwrap_or_else(|| format!("inlet{}", i));
+    taken.insert(name.clone());
+    syn::Ident::new(&name, proc_macro2::Span::call_site())
+}
+
 fn graph_node_evaluator_fn_output<G>(g: G, outlets: &[G::NodeId]) -> syn::ReturnType
 where
     G: Graph,
@@ -572,3 +664,16 @@ where
         }
     }
 }
+
+#[test]
+fn test_graph_node_evaluator_fn_input_ident() {
+    let mut taken = std::collections::HashSet::new();
+    let mut ident = |name: Option<&str>, i| {
+        let name = name.map(|s| s.to_string());
+        graph_node_evaluator_fn_input_ident(name, i, &mut taken).to_string()
+    };
+    assert_eq!(ident(Some("freq"), 0), "inlet_freq");
+    assert_eq!(ident(None, 1), "inlet1");
+    assert_eq!(ident(Some("not an ident"), 2), "inlet2");
+    assert_eq!(ident(Some("freq"), 3), "inlet3");
+}
@@ -23,6 +23,8 @@ use serde::{self, Deserialize, Serialize};
 
 pub mod graph;
 pub mod node;
+pub mod testing;
+pub mod ty;
 
 pub use graph::Edge;
 pub use node::Node;
@@ -46,7 +46,9 @@ pub trait Node {
     ///
     /// Within a **Graph** node, a new function will be generated for each node that signals
     /// **Some**.  If **Some**, a function will be generated with the given **Signature** that
-    /// represents pushing evaluation from this node.
+    /// represents pushing evaluation from this node. Nodes whose push or pull eval functions share
+    /// the same name are combined into a single function that evaluates all of them. Gantz will
+    /// **panic!** if the **EvalFn**s of nodes sharing a name are not otherwise equal.
     ///
     /// Gantz will **panic!** if the returned **Signature** has a return type other than `()`.
     ///
@@ -64,7 +66,9 @@ pub trait Node {
     ///
     /// Within a **Graph** node, a new function will be generated for each node that signals
     /// **Some**.  If **Some**, a function will be generated with the given **Signature** that
-    /// represents pulling evaluation from this node.
+    /// represents pulling evaluation from this node. Nodes whose push or pull eval functions share
+    /// the same name are combined into a single function that evaluates all of them. Gantz will
+    /// **panic!** if the **EvalFn**s of nodes sharing a name are not otherwise equal.
     ///
     /// Gantz will **panic!** if the returned **Signature** has a return type other than `()`.
     ///
@@ -94,6 +98,40 @@ pub trait Node {
     fn crate_deps(&self) -> Vec<CrateDep> {
         vec![]
     }
+
+    /// An optional name for the given input of the node.
+    ///
+    /// Names allow for identifying inputs by something more meaningful than their index, e.g. when
+    /// labelling ports or naming generated function arguments.
+    ///
+    /// By default, all inputs are unnamed.
+    fn input_name(&self, _input: Input) -> Option<String> {
+        None
+    }
+
+    /// An optional name for the given output of the node.
+    ///
+    /// By default, all outputs are unnamed.
+    fn output_name(&self, _output: Output) -> Option<String> {
+        None
+    }
+
+    /// An optional hint describing the expected type of the given input.
+    ///
+    /// Used by the `ty` module to check connections between nodes. Connections to inputs without
+    /// a type hint are always accepted.
+    ///
+    /// By default, no inputs have a type hint.
+    fn input_ty(&self, _input: Input) -> Option<syn::Type> {
+        None
+    }
+
+    /// An optional hint describing the type produced by the given output.
+    ///
+    /// By default, no outputs have a type hint.
+    fn output_ty(&self, _output: Output) -> Option<syn::Type> {
+        None
+    }
 }
 
 /// The method of evaluation used for a node.
@@ -213,7 +251,7 @@ impl Evaluator {
 
 impl<'a, N> Node for &'a N
 where
-    N: Node,
+    N: ?Sized + Node,
 {
     fn evaluator(&self) -> Evaluator {
         (**self).evaluator()
@@ -234,6 +272,22 @@ where
     fn crate_deps(&self) -> Vec<CrateDep> {
         (**self).crate_deps()
     }
+
+    fn input_name(&self, input: Input) -> Option<String> {
+        (**self).input_name(input)
+    }
+
+    fn output_name(&self, output: Output) -> Option<String> {
+        (**self).output_name(output)
+    }
+
+    fn input_ty(&self, input: Input) -> Option<syn::Type> {
+        (**self).input_ty(input)
+    }
+
+    fn output_ty(&self, output: Output) -> Option<syn::Type> {
+        (**self).output_ty(output)
+    }
 }
 
 macro_rules! impl_node_for_ptr {
@@ -258,6 +312,22 @@ macro_rules! impl_node_for_ptr {
             fn crate_deps(&self) -> Vec<CrateDep> {
                 (**self).crate_deps()
             }
+
+            fn input_name(&self, input: Input) -> Option<String> {
+                (**self).input_name(input)
+            }
+
+            fn output_name(&self, output: Output) -> Option<String> {
+                (**self).output_name(output)
+            }
+
+            fn input_ty(&self, input: Input) -> Option<syn::Type> {
+                (**self).input_ty(input)
+            }
+
+            fn output_ty(&self, output: Output) -> Option<syn::Type> {
+                (**self).output_ty(output)
+            }
         }
     };
 }
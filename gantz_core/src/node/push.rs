@@ -78,4 +78,20 @@ where
     fn crate_deps(&self) -> Vec<node::CrateDep> {
         self.node.crate_deps()
     }
+
+    fn input_name(&self, input: node::Input) -> Option<String> {
+        self.node.input_name(input)
+    }
+
+    fn output_name(&self, output: node::Output) -> Option<String> {
+        self.node.output_name(output)
+    }
+
+    fn input_ty(&self, input: node::Input) -> Option<syn::Type> {
+        self.node.input_ty(input)
+    }
+
+    fn output_ty(&self, output: node::Output) -> Option<syn::Type> {
+        self.node.output_ty(output)
+    }
 }
@@ -0,0 +1,190 @@
+//! Items for generating synthetic graphs.
+//!
+//! These are useful for testing and benchmarking codegen against graphs of varying size and shape
+//! without having to compose each graph by hand.
+//!
+//! Enabling the `proptest` feature provides strategies for generating graphs, edges and nodes
+//! within property tests.
+
+use crate::graph::Edge;
+use crate::node::{self, SerdeNode, WithPushEval, WithStateType};
+
+/// The graph type produced by **RandomGraph**.
+pub type Graph =
+    petgraph::stable_graph::StableGraph<Box<dyn SerdeNode>, Edge, petgraph::Directed, usize>;
+
+/// A builder for synthetic, acyclic graphs of **Expr** nodes.
+///
+/// The first `push_nodes` nodes have no inputs and push evaluation enabled. Every following node
+/// takes up to `max_inputs` inputs, each connected to the single output of some earlier node.
+/// Roughly one in `stateful_every` of these nodes is stateful.
+///
+/// The same configuration always produces the same graph.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RandomGraph {
+    /// The total number of nodes within the graph.
+    pub nodes: usize,
+    /// The maximum number of inputs for each non-push node.
+    pub max_inputs: u32,
+    /// The number of nodes that have push evaluation enabled.
+    pub push_nodes: usize,
+    /// One in every `stateful_every` non-push nodes will be stateful. `0` disables state.
+    pub stateful_every: u32,
+    /// The seed used to generate the graph's shape.
+    pub seed: u64,
+}
+
+// A minimal xorshift64* generator. Avoids a dependency and keeps generated graphs stable.
+struct Rng(u64);
+
+impl RandomGraph {
+    /// A builder for a graph with the given number of nodes and default settings.
+    pub fn new(nodes: usize) -> Self {
+        RandomGraph {
+            nodes,
+            max_inputs: 3,
+            push_nodes: 1,
+            stateful_every: 4,
+            seed: 0,
+        }
+    }
+
+    /// Specify the maximum number of inputs for each non-push node.
+    pub fn max_inputs(mut self, max_inputs: u32) -> Self {
+        self.max_inputs = max_inputs;
+        self
+    }
+
+    /// Specify the number of push evaluation nodes.
+    pub fn push_nodes(mut self, push_nodes: usize) -> Self {
+        self.push_nodes = push_nodes;
+        self
+    }
+
+    /// Specify how often non-push nodes should be stateful. `0` disables state.
+    pub fn stateful_every(mut self, stateful_every: u32) -> Self {
+        self.stateful_every = stateful_every;
+        self
+    }
+
+    /// Specify the seed used to generate the graph's shape.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Generate the graph.
+    pub fn build(&self) -> Graph {
+        let mut rng = Rng::new(self.seed);
+        let mut g = Graph::default();
+        let n_push = std::cmp::min(self.push_nodes, self.nodes);
+
+        for i in 0..n_push {
+            let push = node::expr("()")
+                .expect("invalid push expr")
+                .with_push_eval_name(&format!("push{}", i));
+            g.add_node(Box::new(push) as Box<dyn SerdeNode>);
+        }
+
+        for i in n_push..self.nodes {
+            let max_inputs = std::cmp::min(self.max_inputs as usize, i);
+            let n_inputs = rng.below(max_inputs + 1);
+            let stateful = self.stateful_every > 0 && rng.below(self.stateful_every as usize) == 0;
+            let expr = random_node_expr(n_inputs, stateful);
+            let node = node::expr(&expr).expect("invalid generated expr");
+            let n = match stateful {
+                false => g.add_node(Box::new(node) as Box<dyn SerdeNode>),
+                true => {
+                    let node = node.with_state_ty("u32").expect("invalid state type");
+                    g.add_node(Box::new(node) as Box<_>)
+                }
+            };
+            for input in 0..n_inputs {
+                let src = petgraph::graph::NodeIndex::new(rng.below(i));
+                let edge = Edge::new(node::Output(0), node::Input(input as u32));
+                g.add_edge(src, n, edge);
+            }
+        }
+
+        g
+    }
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Scramble the seed with splitmix64 so that nearby seeds produce unrelated sequences. Zero
+        // is a fixed point for xorshift, so the state is also forced to be odd.
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        Rng((z ^ (z >> 31)) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // A value within `0..n`, or `0` if `n` is `0`.
+    fn below(&mut self, n: usize) -> usize {
+        match n {
+            0 => 0,
+            n => (self.next() % n as u64) as usize,
+        }
+    }
+}
+
+/// Generate a synthetic, acyclic graph with the given number of nodes using the given seed.
+///
+/// Shorthand for `RandomGraph::new(nodes).seed(seed).build()`.
+pub fn random_graph(nodes: usize, seed: u64) -> Graph {
+    RandomGraph::new(nodes).seed(seed).build()
+}
+
+/// A strategy producing **RandomGraph** configurations of up to `max_nodes` nodes.
+///
+/// A **Strategy** must produce `Debug` values, so this yields the configuration rather than the
+/// graph. Call `RandomGraph::build` to produce the graph itself.
+#[cfg(feature = "proptest")]
+pub fn arb_random_graph(
+    max_nodes: usize,
+) -> impl proptest::strategy::Strategy<Value = RandomGraph> {
+    use proptest::prelude::*;
+    (0..=max_nodes, 0..5u32, 0..4usize, 0..5u32, any::<u64>()).prop_map(
+        |(nodes, max_inputs, push_nodes, stateful_every, seed)| RandomGraph {
+            nodes,
+            max_inputs,
+            push_nodes,
+            stateful_every,
+            seed,
+        },
+    )
+}
+
+/// A strategy producing edges between outputs and inputs below the given indices.
+#[cfg(feature = "proptest")]
+pub fn arb_edge(outputs: u32, inputs: u32) -> impl proptest::strategy::Strategy<Value = Edge> {
+    use proptest::prelude::*;
+    (0..outputs, 0..inputs).prop_map(|(o, i)| Edge::new(node::Output(o), node::Input(i)))
+}
+
+/// A strategy producing **Expr** nodes with up to `max_inputs` inputs.
+#[cfg(feature = "proptest")]
+pub fn arb_expr(max_inputs: usize) -> impl proptest::strategy::Strategy<Value = node::Expr> {
+    use proptest::prelude::*;
+    (0..=max_inputs).prop_map(|n_inputs| {
+        let expr = random_node_expr(n_inputs, false);
+        node::expr(&expr).expect("invalid generated expr")
+    })
+}
+
+// An expression that consumes all `n_inputs` and produces a `u32`.
+fn random_node_expr(n_inputs: usize, stateful: bool) -> String {
+    let inputs: String = (0..n_inputs).map(|i| format!("#in{}, ", i)).collect();
+    match stateful {
+        false => format!("{{ let _ = ({}); 0u32 }}", inputs),
+        true => format!("{{ let _ = ({}); *state += 1; *state }}", inputs),
+    }
+}
@@ -0,0 +1,78 @@
+//! Optional type checking of the connections between nodes.
+//!
+//! Nodes may provide type hints for their inputs and outputs via `Node::input_ty` and
+//! `Node::output_ty`. The items in this module compare these hints at either end of an edge,
+//! allowing editors to refuse or warn about incompatible connections before codegen.
+//!
+//! Types are compared syntactically, e.g. `f32` and `std::primitive::f32` are considered
+//! different. Connections where either end has no type hint are always accepted, as the Rust
+//! compiler remains the final authority once the graph is compiled.
+
+use crate::graph::Edge;
+use crate::node::Node;
+use petgraph::visit::{Data, EdgeRef, IntoEdgeReferences, IntoNodeReferences, NodeRef};
+use quote::ToTokens;
+use std::collections::HashMap;
+use std::hash::Hash;
+use thiserror::Error;
+
+/// The type hints at either end of a connection do not match.
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error(
+    "output of type `{}` cannot connect to input of type `{}`",
+    .output_ty.to_token_stream(),
+    .input_ty.to_token_stream()
+)]
+pub struct TypeMismatch {
+    /// The type hint of the source node's output.
+    pub output_ty: Box<syn::Type>,
+    /// The type hint of the destination node's input.
+    pub input_ty: Box<syn::Type>,
+}
+
+/// A **TypeMismatch** found for an edge within a graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdgeTypeMismatch<EI> {
+    /// The edge connecting the mismatched output and input.
+    pub edge: EI,
+    /// The mismatched types.
+    pub mismatch: TypeMismatch,
+}
+
+/// Check whether the output of `src` may connect to the input of `dst` described by `edge`.
+pub fn check_connection<S, D>(src: &S, dst: &D, edge: Edge) -> Result<(), TypeMismatch>
+where
+    S: ?Sized + Node,
+    D: ?Sized + Node,
+{
+    match (src.output_ty(edge.output), dst.input_ty(edge.input)) {
+        (Some(output_ty), Some(input_ty)) if output_ty != input_ty => {
+            let output_ty = Box::new(output_ty);
+            let input_ty = Box::new(input_ty);
+            Err(TypeMismatch {
+                output_ty,
+                input_ty,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check every edge within the given graph, returning all type mismatches.
+pub fn check_graph<G>(g: G) -> Vec<EdgeTypeMismatch<G::EdgeId>>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + Data<EdgeWeight = Edge>,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Node,
+{
+    let nodes: HashMap<G::NodeId, G::NodeRef> = g.node_references().map(|n| (n.id(), n)).collect();
+    g.edge_references()
+        .filter_map(|e| {
+            let src = nodes[&e.source()].weight();
+            let dst = nodes[&e.target()].weight();
+            let mismatch = check_connection(src, dst, *e.weight()).err()?;
+            let edge = e.id();
+            Some(EdgeTypeMismatch { edge, mismatch })
+        })
+        .collect()
+}
@@ -0,0 +1,155 @@
+use gantz_core::graph::codegen::{self, Entrypoint, EntrypointKind};
+use gantz_core::graph::{self, GraphNode};
+use gantz_core::node::{self, Node, WithPullEval, WithPushEval};
+use gantz_core::Edge;
+use quote::ToTokens;
+
+type Graph = petgraph::Graph<Box<dyn Node>, Edge>;
+
+// Entrypoints should match the functions within the generated file, in the same order.
+#[test]
+fn test_entrypoints() {
+    let mut g = GraphNode {
+        graph: Graph::new(),
+        inlets: vec![],
+        outlets: vec![],
+    };
+    let inlet = g.add_inlet(Box::new(graph::Inlet::parse("i32").unwrap()) as Box<dyn Node>);
+    let push = node::expr("()").unwrap().with_push_eval_name("bang");
+    let push = g.add_node(Box::new(push) as Box<dyn Node>);
+    let pull = node::expr("println!(\"{}\", #v)")
+        .unwrap()
+        .with_pull_eval_name("print");
+    let pull = g.add_node(Box::new(pull) as Box<dyn Node>);
+    g.add_edge(inlet, pull, Edge::from((0, 0)));
+
+    let entrypoints = codegen::entrypoints(&g.graph, &g.inlets, &g.outlets);
+    let expected = vec![
+        Entrypoint {
+            kind: EntrypointKind::Full,
+            fn_name: graph::FULL_EVAL_FN_NAME.to_string(),
+        },
+        Entrypoint {
+            kind: EntrypointKind::Pull(pull),
+            fn_name: "print".to_string(),
+        },
+        Entrypoint {
+            kind: EntrypointKind::Push(push),
+            fn_name: "bang".to_string(),
+        },
+    ];
+    assert_eq!(entrypoints, expected);
+
+    let file = codegen::file(&g.graph, &g.inlets, &g.outlets);
+    let fn_names: Vec<_> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(item_fn) => Some(item_fn.sig.ident.to_string()),
+            _ => None,
+        })
+        .collect();
+    let entrypoint_names: Vec<_> = entrypoints.iter().map(|e| e.fn_name.clone()).collect();
+    assert_eq!(fn_names, entrypoint_names);
+}
+
+// Nodes whose eval fns share a name should be combined into a single entrypoint.
+//
+//    ---------  ---------
+//    | frame |  | frame | // push_eval
+//    -+-------  -+-------
+//     |          |
+//    -+---      -+---
+//    | 1 |      | 1 |
+//    -+---      -+---
+//     |          |
+//    -+----------+-
+//    |  assert_eq  |
+//    --------------
+#[test]
+fn test_entrypoints_grouped() {
+    let mut g = Graph::new();
+    let frame = || node::expr("()").unwrap().with_push_eval_name("frame");
+    let frame_a = g.add_node(Box::new(frame()) as Box<dyn Node>);
+    let frame_b = g.add_node(Box::new(frame()) as Box<dyn Node>);
+    let one_a = g.add_node(Box::new(node::expr("{ #push; 1 }").unwrap()) as Box<dyn Node>);
+    let one_b = g.add_node(Box::new(node::expr("{ #push; 1 }").unwrap()) as Box<dyn Node>);
+    let assert_eq =
+        g.add_node(Box::new(node::expr("assert_eq!(#l, #r)").unwrap()) as Box<dyn Node>);
+    g.add_edge(frame_a, one_a, Edge::from((0, 0)));
+    g.add_edge(frame_b, one_b, Edge::from((0, 0)));
+    g.add_edge(one_a, assert_eq, Edge::from((0, 0)));
+    g.add_edge(one_b, assert_eq, Edge::from((0, 1)));
+
+    let entrypoints = codegen::entrypoints(&g, &[], &[]);
+    let expected = vec![Entrypoint {
+        kind: EntrypointKind::Group {
+            push: vec![frame_a, frame_b],
+            pull: vec![],
+        },
+        fn_name: "frame".to_string(),
+    }];
+    assert_eq!(entrypoints, expected);
+
+    // A single function should evaluate every node, including both inputs to `assert_eq`.
+    let file = codegen::file(&g, &[], &[]);
+    let fns: Vec<_> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(item_fn) => Some(item_fn),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fns.len(), 1);
+
+    // The group evaluates each `frame` node before its `1` node and `assert_eq` last.
+    let order: Vec<_> = codegen::eval_order(&g, vec![frame_a, frame_b], vec![]).collect();
+    let position = |n| {
+        order
+            .iter()
+            .position(|&o| o == n)
+            .expect("unreachable node")
+    };
+    assert_eq!(order.len(), 5);
+    assert!(position(frame_a) < position(one_a));
+    assert!(position(frame_b) < position(one_b));
+    assert_eq!(order.last(), Some(&assert_eq));
+
+    // The generated function should bind one output per step in that order, with `assert_eq`
+    // taking the outputs of both `1` nodes.
+    let locals: Vec<&syn::Local> = fns[0]
+        .block
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            syn::Stmt::Local(local) => Some(local),
+            _ => None,
+        })
+        .collect();
+    let output = |step: usize| format!("_node{}_output0", step);
+    let bound: Vec<String> = locals
+        .iter()
+        .map(|local| local.pat.to_token_stream().to_string())
+        .collect();
+    let expected: Vec<String> = (0..order.len()).map(output).collect();
+    assert_eq!(bound, expected);
+    let (_, init) = locals.last().unwrap().init.as_ref().expect("no init expr");
+    let init = init.to_token_stream().to_string();
+    assert!(init.contains(&output(position(one_a))));
+    assert!(init.contains(&output(position(one_b))));
+}
+
+// Nodes sharing an eval fn name must have equal signatures.
+#[test]
+#[should_panic(expected = "differing signatures or attributes")]
+fn test_entrypoints_grouped_mismatch() {
+    let mut g = Graph::new();
+    let frame_a = node::expr("()").unwrap().with_push_eval_name("frame");
+    let frame_b = node::expr("()")
+        .unwrap()
+        .with_push_eval_fn(syn::parse_quote! { fn frame(dt: f32) {} });
+    g.add_node(Box::new(frame_a) as Box<dyn Node>);
+    g.add_node(Box::new(frame_b) as Box<dyn Node>);
+    codegen::eval_groups(&g);
+}
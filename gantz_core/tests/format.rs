@@ -0,0 +1,111 @@
+// Tests for the versioned graph serialization format.
+
+use gantz_core::graph::{format, GraphNode};
+use gantz_core::testing::{self, Graph};
+use petgraph::graph::NodeIndex;
+
+fn to_json(g: &Graph) -> String {
+    let mut bytes = vec![];
+    format::serialize(g, &mut serde_json::Serializer::new(&mut bytes)).unwrap();
+    String::from_utf8(bytes).unwrap()
+}
+
+fn from_json(json: &str) -> serde_json::Result<Graph> {
+    format::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+fn graph_node_to_json(g: &GraphNode<Graph>) -> String {
+    let mut bytes = vec![];
+    format::graph_node::serialize(g, &mut serde_json::Serializer::new(&mut bytes)).unwrap();
+    String::from_utf8(bytes).unwrap()
+}
+
+fn graph_node_from_json(json: &str) -> serde_json::Result<GraphNode<Graph>> {
+    format::graph_node::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+// A random graph with a few nodes removed so that the round trip must preserve index holes.
+fn graph_with_holes() -> Graph {
+    let mut g = testing::random_graph(16, 3);
+    for i in &[2, 7, 11] {
+        g.remove_node(NodeIndex::new(*i));
+    }
+    g
+}
+
+// The graph with holes wrapped in a graph node, with inlets and outlets on either side of a hole.
+fn graph_node_with_holes() -> GraphNode<Graph> {
+    let graph = graph_with_holes();
+    let inlets = vec![NodeIndex::new(1), NodeIndex::new(3)];
+    let outlets = vec![NodeIndex::new(15), NodeIndex::new(8)];
+    GraphNode {
+        graph,
+        inlets,
+        outlets,
+    }
+}
+
+// Asserts that the graph nodes share the same node indices, inlets, outlets and graph data.
+fn assert_graph_node_eq(a: &GraphNode<Graph>, b: &GraphNode<Graph>) {
+    let ixs: Vec<_> = a.node_indices().collect();
+    let ixs2: Vec<_> = b.node_indices().collect();
+    assert_eq!(ixs, ixs2);
+    assert_eq!(a.inlets, b.inlets);
+    assert_eq!(a.outlets, b.outlets);
+    assert_eq!(to_json(&a.graph), to_json(&b.graph));
+}
+
+#[test]
+fn test_format_roundtrip() {
+    let g = graph_with_holes();
+    let json = to_json(&g);
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["version"], format::VERSION);
+    let g2 = from_json(&json).unwrap();
+    assert_eq!(json, to_json(&g2));
+    let ixs: Vec<_> = g.node_indices().collect();
+    let ixs2: Vec<_> = g2.node_indices().collect();
+    assert_eq!(ixs, ixs2);
+}
+
+// Graphs serialized before the format was versioned have no `version` field.
+#[test]
+fn test_format_unversioned() {
+    let g = graph_with_holes();
+    let json = serde_json::to_string(&g).unwrap();
+    let g2 = from_json(&json).unwrap();
+    assert_eq!(to_json(&g), to_json(&g2));
+}
+
+// Unknown fields are ignored, but unsupported versions are rejected.
+#[test]
+fn test_format_future_version() {
+    let g = graph_with_holes();
+    let mut value: serde_json::Value = serde_json::from_str(&to_json(&g)).unwrap();
+    value["unknown_field"] = serde_json::json!([1, 2, 3]);
+    assert!(from_json(&value.to_string()).is_ok());
+    value["version"] = serde_json::json!(format::VERSION + 1);
+    assert!(from_json(&value.to_string()).is_err());
+}
+
+#[test]
+fn test_format_graph_node_roundtrip() {
+    let g = graph_node_with_holes();
+    let json = graph_node_to_json(&g);
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["graph"]["version"], format::VERSION);
+    let g2 = graph_node_from_json(&json).unwrap();
+    assert_eq!(json, graph_node_to_json(&g2));
+    assert_graph_node_eq(&g, &g2);
+}
+
+// Graph nodes serialized before the format was versioned have no `version` field on their graph.
+#[test]
+fn test_format_graph_node_unversioned() {
+    let g = graph_node_with_holes();
+    let json = serde_json::to_string(&g).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(value["graph"].get("version").is_none());
+    let g2 = graph_node_from_json(&json).unwrap();
+    assert_graph_node_eq(&g, &g2);
+}
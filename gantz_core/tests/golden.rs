@@ -0,0 +1,185 @@
+// Golden-file tests for generated source.
+//
+// Each test generates the source for a representative graph and compares the pretty-printed
+// result against the checked-in file at `tests/golden/<name>.rs`.
+//
+// To accept changes in generated code, run the tests with `GANTZ_BLESS=1` and review the diff.
+
+use gantz_core::graph::codegen;
+use gantz_core::node::{self, Node, WithPullEval, WithPushEval, WithStateType};
+use gantz_core::testing::RandomGraph;
+use gantz_core::Edge;
+use std::path::PathBuf;
+
+type Graph = petgraph::Graph<Box<dyn Node>, Edge>;
+
+fn node_push() -> node::Push<node::Expr> {
+    node::expr("()").unwrap().with_push_eval_name("push")
+}
+
+fn node_int(i: i32) -> node::Expr {
+    node::expr(&format!("{{ #push; {} }}", i)).unwrap()
+}
+
+fn node_add() -> node::Expr {
+    node::expr("#l + #r").unwrap()
+}
+
+fn node_assert_eq() -> node::Expr {
+    node::expr("assert_eq!(#l, #r)").unwrap()
+}
+
+fn node_counter() -> node::State<node::Expr> {
+    node::expr(r#"{ #push; let count = *state; *state += 1; count }"#)
+        .unwrap()
+        .with_state_ty("u32")
+        .unwrap()
+}
+
+// A node for testing the `Fn` evaluator variant.
+struct Mul;
+
+impl Node for Mul {
+    fn evaluator(&self) -> node::Evaluator {
+        let fn_item = syn::parse_quote! {
+            fn mul<T>(a: T, b: T) -> T
+            where
+                T: std::ops::Mul<T, Output = T>,
+            {
+                a * b
+            }
+        };
+        node::Evaluator::Fn { fn_item }
+    }
+}
+
+// Compare the source generated for `g` against the golden file with the given name.
+fn assert_golden<G>(name: &str, g: G)
+where
+    G: petgraph::visit::GraphRef
+        + petgraph::visit::IntoEdgesDirected
+        + petgraph::visit::IntoNodeReferences
+        + petgraph::visit::NodeIndexable
+        + petgraph::visit::Visitable,
+    G: petgraph::visit::Data<EdgeWeight = Edge>,
+    G::NodeId: Eq + std::hash::Hash,
+    G::NodeWeight: Node,
+{
+    let file = codegen::file(g, &[], &[]);
+    let src = prettyplease::unparse(&file);
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(name)
+        .with_extension("rs");
+    if std::env::var_os("GANTZ_BLESS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &src).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "failed to read golden file {}: {} (run with `GANTZ_BLESS=1` to create it)",
+            path.display(),
+            err,
+        )
+    });
+    assert!(
+        src == expected,
+        "generated source for `{}` differs from {}\n\nexpected:\n{}\nfound:\n{}\n\
+         (run with `GANTZ_BLESS=1` to accept the new output)",
+        name,
+        path.display(),
+        expected,
+        src,
+    );
+}
+
+//    --------
+//    | push | // push_eval
+//    -+------
+//     |
+//     |---------
+//     |        |
+//    -+-----   |
+//    | one |   |
+//    -+-----   |
+//     |\       |
+//    -+---+-  -+-----
+//    | add |  | two |
+//    -+-----  -+-----
+//     |        |
+//    -+--------+-
+//    |assert_eq |
+//    ------------
+#[test]
+fn golden_add() {
+    let mut g = Graph::new();
+    let push = g.add_node(Box::new(node_push()));
+    let one = g.add_node(Box::new(node_int(1)));
+    let add = g.add_node(Box::new(node_add()));
+    let two = g.add_node(Box::new(node_int(2)));
+    let assert_eq = g.add_node(Box::new(node_assert_eq()));
+    g.add_edge(push, one, Edge::from((0, 0)));
+    g.add_edge(push, two, Edge::from((0, 0)));
+    g.add_edge(one, add, Edge::from((0, 0)));
+    g.add_edge(one, add, Edge::from((0, 1)));
+    g.add_edge(add, assert_eq, Edge::from((0, 0)));
+    g.add_edge(two, assert_eq, Edge::from((0, 1)));
+    assert_golden("add", &g);
+}
+
+// The same shape as `golden_add`, but using an `Fn` evaluator node in place of `add`.
+#[test]
+fn golden_fn_evaluator() {
+    let mut g = Graph::new();
+    let push = g.add_node(Box::new(node_push()));
+    let two = g.add_node(Box::new(node_int(2)));
+    let mul = g.add_node(Box::new(Mul));
+    let four = g.add_node(Box::new(node_int(4)));
+    let assert_eq = g.add_node(Box::new(node_assert_eq()));
+    g.add_edge(push, two, Edge::from((0, 0)));
+    g.add_edge(push, four, Edge::from((0, 0)));
+    g.add_edge(two, mul, Edge::from((0, 0)));
+    g.add_edge(two, mul, Edge::from((0, 1)));
+    g.add_edge(mul, assert_eq, Edge::from((0, 0)));
+    g.add_edge(four, assert_eq, Edge::from((0, 1)));
+    assert_golden("fn_evaluator", &g);
+}
+
+// A push node driving a chain of stateful counters.
+#[test]
+fn golden_counters() {
+    let mut g = Graph::new();
+    let push = g.add_node(Box::new(node_push()));
+    let a = g.add_node(Box::new(node_counter()));
+    let b = g.add_node(Box::new(node_counter()));
+    g.add_edge(push, a, Edge::from((0, 0)));
+    g.add_edge(a, b, Edge::from((0, 0)));
+    assert_golden("counters", &g);
+}
+
+// Pull evaluation from the final node in a chain.
+#[test]
+fn golden_pull() {
+    let mut g = Graph::new();
+    let one = g.add_node(Box::new(node::expr("1").unwrap()));
+    let two = g.add_node(Box::new(node::expr("2").unwrap()));
+    let add = g.add_node(Box::new(node_add()));
+    let pull = node::expr("println!(\"{}\", #v)")
+        .unwrap()
+        .with_pull_eval_name("pull");
+    let pull = g.add_node(Box::new(pull));
+    g.add_edge(one, add, Edge::from((0, 0)));
+    g.add_edge(two, add, Edge::from((0, 1)));
+    g.add_edge(add, pull, Edge::from((0, 0)));
+    assert_golden("pull", &g);
+}
+
+// A larger synthetic graph with multiple push nodes and stateful nodes.
+#[test]
+fn golden_random() {
+    let g = RandomGraph::new(24).push_nodes(2).seed(1).build();
+    let g = g.map(|_, n| n.node(), |_, e| *e);
+    assert_golden("random", &g);
+}
@@ -0,0 +1,15 @@
+#![allow(unused_braces)]
+#[no_mangle]
+pub fn push(_node_states: &mut [&mut dyn std::any::Any]) {
+    let _node0_output0 = ();
+    let _node1_output0 = {
+        { _node0_output0 };
+        1
+    };
+    let _node2_output0 = { _node1_output0 } + { _node1_output0.clone() };
+    let _node3_output0 = {
+        { _node0_output0 };
+        2
+    };
+    let _node4_output0 = assert_eq!({ _node2_output0 }, { _node3_output0 });
+}
@@ -0,0 +1,27 @@
+#![allow(unused_braces)]
+#[no_mangle]
+pub fn push(_node_states: &mut [&mut dyn std::any::Any]) {
+    let _node0_output0 = ();
+    let _node1_output0 = {
+        let state: &mut u32 = _node_states[0usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            { _node0_output0 };
+            let count = *state;
+            *state += 1;
+            count
+        }
+    };
+    let _node2_output0 = {
+        let state: &mut u32 = _node_states[1usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            { _node1_output0 };
+            let count = *state;
+            *state += 1;
+            count
+        }
+    };
+}
@@ -0,0 +1,21 @@
+#![allow(unused_braces)]
+fn mul<T>(a: T, b: T) -> T
+where
+    T: std::ops::Mul<T, Output = T>,
+{
+    a * b
+}
+#[no_mangle]
+pub fn push(_node_states: &mut [&mut dyn std::any::Any]) {
+    let _node0_output0 = ();
+    let _node1_output0 = {
+        { _node0_output0 };
+        2
+    };
+    let _node2_output0 = mul({ _node1_output0 }, { _node1_output0.clone() });
+    let _node3_output0 = {
+        { _node0_output0 };
+        4
+    };
+    let _node4_output0 = assert_eq!({ _node2_output0 }, { _node3_output0 });
+}
@@ -0,0 +1,8 @@
+#![allow(unused_braces)]
+#[no_mangle]
+pub fn pull(_node_states: &mut [&mut dyn std::any::Any]) {
+    let _node0_output0 = 2;
+    let _node1_output0 = 1;
+    let _node2_output0 = { _node1_output0 } + { _node0_output0 };
+    let _node3_output0 = println!("{}", { _node2_output0 });
+}
@@ -0,0 +1,175 @@
+#![allow(unused_braces)]
+#[no_mangle]
+pub fn push0(_node_states: &mut [&mut dyn std::any::Any]) {
+    let _node0_output0 = ();
+    let _node1_output0 = {
+        let state: &mut u32 = _node_states[0usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            let _ = ({ _node0_output0 },);
+            *state += 1;
+            *state
+        }
+    };
+    let _node2_output0 = {
+        let state: &mut u32 = _node_states[1usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            let _ = ((), { _node0_output0.clone() }, { _node1_output0 });
+            *state += 1;
+            *state
+        }
+    };
+    let _node3_output0 = {
+        let _ = ((), { _node1_output0.clone() }, { _node2_output0 });
+        0u32
+    };
+    let _node4_output0 = {
+        let _ = ({ _node0_output0 }, { _node3_output0 });
+        0u32
+    };
+    let _node5_output0 = {
+        let _ = ({ _node3_output0.clone() }, { _node4_output0 });
+        0u32
+    };
+    let _node6_output0 = {
+        let _ = ({ _node0_output0 }, { _node2_output0.clone() }, { _node2_output0 });
+        0u32
+    };
+    let _node7_output0 = {
+        let _ = ((), { _node6_output0 });
+        0u32
+    };
+    let _node8_output0 = {
+        let _ = ({ _node7_output0 },);
+        0u32
+    };
+    let _node9_output0 = {
+        let _ = ({ _node6_output0.clone() },);
+        0u32
+    };
+    let _node10_output0 = {
+        let _ = ({ _node9_output0 }, { _node4_output0 });
+        0u32
+    };
+    let _node11_output0 = {
+        let state: &mut u32 = _node_states[2usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            let _ = ((), { _node10_output0 });
+            *state += 1;
+            *state
+        }
+    };
+    let _node12_output0 = {
+        let _ = ({ _node9_output0 }, ());
+        0u32
+    };
+    let _node13_output0 = {
+        let _ = ({ _node9_output0 }, ());
+        0u32
+    };
+    let _node14_output0 = {
+        let state: &mut u32 = _node_states[3usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            let _ = ({ _node13_output0 }, { _node0_output0.clone() }, ());
+            *state += 1;
+            *state
+        }
+    };
+    let _node15_output0 = {
+        let _ = ({ _node3_output0.clone() }, { _node6_output0 });
+        0u32
+    };
+    let _node16_output0 = {
+        let _ = ({ _node15_output0 },);
+        0u32
+    };
+}
+#[no_mangle]
+pub fn push1(_node_states: &mut [&mut dyn std::any::Any]) {
+    let _node0_output0 = ();
+    let _node1_output0 = {
+        let state: &mut u32 = _node_states[0usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            let _ = ({ _node0_output0 }, (), ());
+            *state += 1;
+            *state
+        }
+    };
+    let _node2_output0 = {
+        let _ = ({ _node0_output0 }, (), { _node1_output0 });
+        0u32
+    };
+    let _node3_output0 = {
+        let _ = ((), { _node2_output0 });
+        0u32
+    };
+    let _node4_output0 = {
+        let _ = ({ _node2_output0.clone() }, { _node3_output0 });
+        0u32
+    };
+    let _node5_output0 = {
+        let _ = ((), { _node1_output0.clone() }, { _node1_output0 });
+        0u32
+    };
+    let _node6_output0 = {
+        let _ = ({ _node0_output0 }, { _node5_output0 });
+        0u32
+    };
+    let _node7_output0 = {
+        let _ = ({ _node6_output0 },);
+        0u32
+    };
+    let _node8_output0 = {
+        let _ = ({ _node5_output0.clone() },);
+        0u32
+    };
+    let _node9_output0 = {
+        let _ = ({ _node8_output0 }, { _node3_output0 });
+        0u32
+    };
+    let _node10_output0 = {
+        let state: &mut u32 = _node_states[1usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            let _ = ((), { _node9_output0 });
+            *state += 1;
+            *state
+        }
+    };
+    let _node11_output0 = {
+        let _ = ({ _node8_output0 }, { _node0_output0.clone() });
+        0u32
+    };
+    let _node12_output0 = {
+        let _ = ({ _node8_output0 }, { _node0_output0.clone() });
+        0u32
+    };
+    let _node13_output0 = {
+        let state: &mut u32 = _node_states[2usize]
+            .downcast_mut::<u32>()
+            .expect("failed to downcast to expected node state type");
+        {
+            let _ = ({ _node12_output0 }, (), ());
+            *state += 1;
+            *state
+        }
+    };
+    let _node14_output0 = {
+        let _ = ({ _node2_output0.clone() }, { _node5_output0 });
+        0u32
+    };
+    let _node15_output0 = {
+        let _ = ({ _node14_output0 },);
+        0u32
+    };
+}
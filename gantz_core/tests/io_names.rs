@@ -0,0 +1,105 @@
+// Tests for the names of graph node inputs and outputs provided by inlets and outlets.
+
+use gantz_core::graph::{self, AddNode, EvaluatorFnBlock, GraphNode, Inlet, Outlet};
+use gantz_core::node::{self, Node};
+use gantz_core::Edge;
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::{Data, GraphBase};
+
+// A minimal graph capable of being used within a **GraphNode**.
+#[derive(Default)]
+struct Graph(petgraph::Graph<Box<dyn Node>, Edge>);
+
+impl GraphBase for Graph {
+    type NodeId = NodeIndex;
+    type EdgeId = EdgeIndex;
+}
+
+impl Data for Graph {
+    type NodeWeight = Box<dyn Node>;
+    type EdgeWeight = Edge;
+}
+
+impl AddNode for Graph {
+    fn add_node(&mut self, n: Self::NodeWeight) -> Self::NodeId {
+        self.0.add_node(n)
+    }
+}
+
+impl EvaluatorFnBlock for Graph {
+    fn evaluator_fn_block(
+        &self,
+        _: &[NodeIndex],
+        _: &[NodeIndex],
+        _: &syn::Signature,
+    ) -> syn::Block {
+        syn::parse_quote! {{ unimplemented!() }}
+    }
+}
+
+impl graph::Graph for Graph {
+    type Node = Box<dyn Node>;
+    fn node(&self, id: Self::NodeId) -> Option<&Self::Node> {
+        self.0.node_weight(id)
+    }
+    fn state_type(&self) -> syn::Type {
+        syn::parse_quote!(())
+    }
+}
+
+// A graph node with one named and one unnamed inlet, and one named outlet.
+fn graph_node() -> GraphNode<Graph> {
+    let mut g = GraphNode::<Graph>::default();
+    g.add_inlet(Box::new(Inlet::parse("i32").unwrap().with_name("lhs")) as Box<dyn Node>);
+    g.add_inlet(Box::new(Inlet::parse("i32").unwrap()) as Box<dyn Node>);
+    g.add_outlet(Box::new(Outlet::parse("i32").unwrap().with_name("sum")) as Box<dyn Node>);
+    g
+}
+
+#[test]
+fn test_graph_node_io_names() {
+    let g = graph_node();
+    assert_eq!(g.input_name(node::Input(0)), Some("lhs".to_string()));
+    assert_eq!(g.input_name(node::Input(1)), None);
+    assert_eq!(g.input_name(node::Input(2)), None);
+    assert_eq!(g.output_name(node::Output(0)), Some("sum".to_string()));
+    assert_eq!(g.output_name(node::Output(1)), None);
+}
+
+#[test]
+fn test_graph_node_evaluator_named_inlet() {
+    let fn_item = match graph_node().evaluator() {
+        node::Evaluator::Fn { fn_item } => fn_item,
+        _ => panic!("expected a graph node to produce a function evaluator"),
+    };
+    let args: Vec<String> = fn_item
+        .sig
+        .inputs
+        .iter()
+        .map(|arg| match arg {
+            syn::FnArg::Typed(pat_ty) => match &*pat_ty.pat {
+                syn::Pat::Ident(pat) => pat.ident.to_string(),
+                _ => panic!("expected an ident pattern"),
+            },
+            _ => panic!("expected a typed argument"),
+        })
+        .collect();
+    assert_eq!(args, vec!["inlet_lhs", "inlet1"]);
+}
+
+#[test]
+fn test_io_names_serde_roundtrip() {
+    let inlet = Inlet::parse("i32").unwrap().with_name("lhs");
+    let json = serde_json::to_string(&inlet).unwrap();
+    assert_eq!(serde_json::from_str::<Inlet>(&json).unwrap(), inlet);
+
+    let outlet = Outlet::parse("i32").unwrap().with_name("sum");
+    let json = serde_json::to_string(&outlet).unwrap();
+    assert_eq!(serde_json::from_str::<Outlet>(&json).unwrap(), outlet);
+
+    // Unnamed ports omit the field and data without it deserializes as unnamed.
+    let inlet = Inlet::parse("i32").unwrap();
+    let json = serde_json::to_string(&inlet).unwrap();
+    assert!(!json.contains("name"));
+    assert_eq!(serde_json::from_str::<Inlet>(&json).unwrap().name, None);
+}
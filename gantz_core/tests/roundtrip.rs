@@ -0,0 +1,52 @@
+// Property tests checking that graphs, edges and nodes survive serde round trips.
+
+use gantz_core::graph::codegen;
+use gantz_core::node::Node;
+use gantz_core::testing::{self, Graph};
+use proptest::prelude::*;
+use quote::ToTokens;
+
+// Round trip the graph through JSON.
+fn roundtrip(g: &Graph) -> (String, Graph) {
+    let json = serde_json::to_string(g).unwrap();
+    let g = serde_json::from_str(&json).unwrap();
+    (json, g)
+}
+
+// The generated source for the given graph.
+fn src(g: &Graph) -> String {
+    let g = g.map(|_, n| n.node(), |_, e| *e);
+    codegen::file(&g, &[], &[]).into_token_stream().to_string()
+}
+
+proptest! {
+    #[test]
+    fn graph_serde_is_stable(rg in testing::arb_random_graph(64)) {
+        let g = rg.build();
+        let (json, g2) = roundtrip(&g);
+        let (json2, _) = roundtrip(&g2);
+        prop_assert_eq!(json, json2);
+    }
+
+    #[test]
+    fn graph_codegen_survives_serde(rg in testing::arb_random_graph(64)) {
+        let g = rg.build();
+        let (_, g2) = roundtrip(&g);
+        prop_assert_eq!(src(&g), src(&g2));
+    }
+
+    #[test]
+    fn edge_serde_roundtrip(edge in testing::arb_edge(8, 8)) {
+        let json = serde_json::to_string(&edge).unwrap();
+        let edge2 = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(edge, edge2);
+    }
+
+    #[test]
+    fn expr_serde_roundtrip(expr in testing::arb_expr(8)) {
+        let json = serde_json::to_string(&expr).unwrap();
+        let expr2: gantz_core::node::Expr = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(expr.to_string(), expr2.to_string());
+        prop_assert_eq!(expr.evaluator().n_inputs(), expr2.evaluator().n_inputs());
+    }
+}
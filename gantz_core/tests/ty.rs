@@ -0,0 +1,47 @@
+use gantz_core::graph::{GraphNode, Inlet, Outlet};
+use gantz_core::node::{self, Node};
+use gantz_core::{ty, Edge};
+
+type Graph = petgraph::Graph<Box<dyn Node>, Edge>;
+
+// Only connections where both ends have differing type hints are rejected.
+//
+//    ---------------
+//    | Inlet (i32) |
+//    -+-------------
+//     |\
+//     | \---------------
+//     |                |
+//    -+-------------  -+--------------
+//    | Outlet(i32) |  | Outlet (f32) |
+//    ---------------  ----------------
+#[test]
+fn test_check_graph() {
+    let mut g = GraphNode {
+        graph: Graph::new(),
+        inlets: vec![],
+        outlets: vec![],
+    };
+    let inlet = g.add_inlet(Box::new(Inlet::parse("i32").unwrap()) as Box<dyn Node>);
+    let out_i32 = g.add_outlet(Box::new(Outlet::parse("i32").unwrap()) as Box<dyn Node>);
+    let out_f32 = g.add_outlet(Box::new(Outlet::parse("f32").unwrap()) as Box<dyn Node>);
+    let expr = g.add_node(Box::new(node::expr("#v").unwrap()) as Box<dyn Node>);
+    g.add_edge(inlet, out_i32, Edge::from((0, 0)));
+    let bad = g.add_edge(inlet, out_f32, Edge::from((0, 0)));
+    g.add_edge(inlet, expr, Edge::from((0, 0)));
+
+    let mismatches = ty::check_graph(&g.graph);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].edge, bad);
+    assert_eq!(
+        mismatches[0].mismatch.to_string(),
+        "output of type `i32` cannot connect to input of type `f32`",
+    );
+
+    // Connections to nodes without type hints are always accepted.
+    let src = Inlet::parse("i32").unwrap();
+    let dst = Outlet::parse("i32").unwrap();
+    assert!(ty::check_connection(&src, &dst, Edge::from((0, 0))).is_ok());
+    let dst = node::expr("#v").unwrap();
+    assert!(ty::check_connection(&src, &dst, Edge::from((0, 0))).is_ok());
+}
@@ -0,0 +1,37 @@
+use gantz_core::graph::codegen::{self, Warning};
+use gantz_core::node::{self, Node, WithPushEval};
+use gantz_core::Edge;
+
+type Graph = petgraph::Graph<Box<dyn Node>, Edge>;
+
+//    --------
+//    | push | // push_eval
+//    -+------
+//     |
+//    -+-----   -------
+//    | add |   | one |
+//    -+-----   -+-----
+//
+// `add` is missing its right input, `one` is unreachable and the final edge refers to a
+// non-existent input on `add`.
+#[test]
+fn test_warnings() {
+    let mut g = Graph::new();
+    let push = node::expr("()").unwrap().with_push_eval_name("push");
+    let push = g.add_node(Box::new(push) as Box<dyn Node>);
+    let add = g.add_node(Box::new(node::expr("#l + #r").unwrap()) as Box<dyn Node>);
+    let one = g.add_node(Box::new(node::expr("1").unwrap()) as Box<dyn Node>);
+    g.add_edge(push, add, Edge::from((0, 0)));
+    let invalid = g.add_edge(push, add, Edge::from((0, 2)));
+
+    let warnings = codegen::warnings(&g, &[], &[]);
+    let expected = vec![
+        Warning::UnconnectedInput {
+            node: add,
+            input: node::Input(1),
+        },
+        Warning::Unreachable { node: one },
+        Warning::InvalidEdge { edge: invalid },
+    ];
+    assert_eq!(warnings, expected);
+}